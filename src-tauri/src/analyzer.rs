@@ -1,23 +1,96 @@
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::fs;
-use std::io::{BufRead, Read};
+use std::io::{BufRead, Read, Seek};
 use std::process::Command;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use tauri::Manager;
+use crate::build_info::{build_build_info, compute_build_info_findings, BuildInfo};
+use crate::dwarf::{dwarf_for_session, DwarfIndex};
+use crate::elf_symbols::apply_symbol_sections;
+use crate::forecast::{
+    compute_region_findings, compute_region_forecasts, record_usage_snapshot, RegionForecast, RegionThresholds,
+};
 use crate::fs_utils::write_atomic;
+use crate::fuzzy::FuzzyPattern;
+use crate::identity::{read_firmware_identity, FirmwareIdentity};
+use crate::layout::{build_address_layout, compute_layout_findings, AddressLayout};
+use crate::linker_script::parse_linker_script_file;
+use crate::linkage::{build_linkage_report, compute_linkage_findings, LinkageReport};
+use crate::mapping_symbols::{apply_code_data_split, compute_code_data_findings, parse_mapping_symbols, CodeDataReport};
+use crate::reservations::{build_stack_heap_report, compute_stack_heap_findings, StackHeapReport, DEFAULT_STACK_MARGIN};
+use crate::section_rules::{rules_signature, SectionClass, SectionClassifier, SectionRule};
+use crate::svd::{parse_svd_file, PeripheralMatch, SvdDevice};
+use crate::symbol_index::SymbolIndex;
+use crate::symbol_query::SymbolFilter;
 use crate::toolchain::{resolve_toolchain, ToolchainConfig, ToolchainPaths};
 
+/// Open analyses keyed by session id, which is the analysis cache key. Several firmwares
+/// (e.g. the two cores of a dual-core part) can be queried side by side.
 #[derive(Default)]
 pub struct AppState {
-    pub symbols: Mutex<Vec<SymbolInfo>>,
+    pub sessions: Mutex<HashMap<String, Arc<Session>>>,
+}
+
+#[derive(Default)]
+pub struct Session {
+    pub symbols: Mutex<SymbolIndex>,
+    pub analysis: Mutex<Option<AnalysisResult>>,
+    /// DWARF index, built on first use.
+    pub dwarf: Mutex<Option<Arc<DwarfIndex>>>,
+    pub svd: Mutex<Option<Arc<SvdDevice>>>,
+}
+
+impl AppState {
+    pub fn session(&self, id: &str) -> Result<Arc<Session>, String> {
+        let sessions = self.sessions.lock().map_err(|_| "Failed to read sessions.".to_string())?;
+        sessions
+            .get(id.trim())
+            .cloned()
+            .ok_or_else(|| format!("Session {} is not open. Run analysis first.", id.trim()))
+    }
+
+    /// Registers an analysed session. Analysis fills a fresh `Session` and only swaps it in
+    /// here, so a failed run leaves the open session of the same key untouched. A session of
+    /// the same ELF under another cache key (different map, rules or toolchain) is replaced
+    /// rather than kept alongside it.
+    fn commit_session(&self, id: &str, elf_path: &str, session: Arc<Session>) -> Result<(), String> {
+        let mut sessions = self.sessions.lock().map_err(|_| "Failed to read sessions.".to_string())?;
+        sessions.retain(|other_id, other| {
+            other_id == id
+                || other
+                    .analysis
+                    .lock()
+                    .map(|analysis| analysis.as_ref().map(|a| a.meta.elf_path != elf_path).unwrap_or(true))
+                    .unwrap_or(true)
+        });
+        sessions.insert(id.to_string(), session);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionInfo {
+    pub id: String,
+    pub elf_path: String,
+    pub map_path: Option<String>,
+    pub build_id: Option<String>,
+    pub symbol_count: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyzeParams {
     pub elf_path: String,
     pub map_path: Option<String>,
+    pub linker_script_path: Option<String>,
     pub toolchain: Option<ToolchainConfig>,
+    pub region_thresholds: Option<RegionThresholds>,
+    pub stack_margin_bytes: Option<u64>,
+    pub svd_path: Option<String>,
+    /// Classification for sections the built-in prefixes miss, from the user's settings.
+    #[serde(default)]
+    pub section_rules: Option<Vec<SectionRule>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,12 +98,27 @@ pub struct AnalysisResult {
     pub meta: AnalysisMeta,
     pub summary: AnalysisSummary,
     pub sections: Vec<SectionInfo>,
+    pub segments: Vec<ProgramSegment>,
+    pub layout: AddressLayout,
+    pub linkage: LinkageReport,
+    pub stack_heap: StackHeapReport,
+    #[serde(default)]
+    pub forecasts: Vec<RegionForecast>,
+    #[serde(default)]
+    pub code_data: CodeDataReport,
+    #[serde(default)]
+    pub identity: FirmwareIdentity,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisMeta {
     pub elf_path: String,
     pub map_path: Option<String>,
+    pub linker_script_path: Option<String>,
+    #[serde(default)]
+    pub svd_path: Option<String>,
+    #[serde(default)]
+    pub build_info: BuildInfo,
     pub toolchain: ToolchainPaths,
     pub cache: CacheMeta,
 }
@@ -65,15 +153,47 @@ pub struct SectionInfo {
     pub size: u64,
     pub vma: Option<String>,
     pub lma: Option<String>,
+    #[serde(default)]
+    pub align: u64,
+    #[serde(default)]
+    pub flags: Vec<String>,
+    #[serde(default)]
+    pub file_offset: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramSegment {
+    pub kind: String,
+    pub offset: u64,
+    pub vaddr: String,
+    pub paddr: String,
+    pub filesz: u64,
+    pub memsz: u64,
+    pub flags: String,
+    pub align: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SymbolInfo {
     pub name: String,
     pub size: u64,
     pub addr: Option<String>,
     pub kind: String,
+    /// Coarse class (text/rodata/data/bss/other) from the nm type letter.
     pub section_guess: String,
+    /// Defining ELF section from the symbol table, with its raw `st_shndx`.
+    #[serde(default)]
+    pub section: Option<String>,
+    #[serde(default)]
+    pub section_index: Option<u32>,
+    /// Literal pool / jump table bytes inside a function, from ARM mapping symbols.
+    #[serde(default)]
+    pub data_bytes: Option<u64>,
+    /// Input object and archive the symbol's address falls in, from the MAP file.
+    #[serde(default)]
+    pub object: Option<String>,
+    #[serde(default)]
+    pub library: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,6 +217,8 @@ pub struct MemoryRegion {
     pub used: Option<u64>,
     pub padding_bytes: Option<u64>,
     pub sources: Vec<RegionSource>,
+    #[serde(default)]
+    pub usage_source: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -123,6 +245,7 @@ pub struct CacheMeta {
 pub struct PcLookupResult {
     pub address: String,
     pub symbol: Option<PcLookupSymbol>,
+    pub peripheral: Option<PeripheralMatch>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,8 +255,22 @@ pub struct PcLookupSymbol {
     pub size: u64,
     pub kind: String,
     pub section_guess: String,
+    pub section: Option<String>,
     pub offset: u64,
 }
+
+/// `path` is a C expression such as `g_ctx.rx_buf[12]`; `member_offset` is the byte
+/// offset inside that leaf and `padding` marks addresses that fall between members.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLookupResult {
+    pub address: String,
+    pub symbol: Option<PcLookupSymbol>,
+    pub peripheral: Option<PeripheralMatch>,
+    pub path: Option<String>,
+    pub type_name: Option<String>,
+    pub member_offset: u64,
+    pub padding: bool,
+}
 #[tauri::command]
 pub fn analyze_firmware(
     app: tauri::AppHandle,
@@ -141,51 +278,101 @@ pub fn analyze_firmware(
     params: AnalyzeParams,
 ) -> Result<AnalysisResult, String> {
     validate_inputs(&params)?;
+    let classifier = SectionClassifier::new(params.section_rules.as_deref().unwrap_or_default())?;
     let toolchain_paths = resolve_toolchain(params.toolchain.as_ref())?;
-    let cache_key = build_cache_key(&toolchain_paths, &params)?;
+    let identity = read_firmware_identity(&params.elf_path)?;
+    let cache_key = build_cache_key(&toolchain_paths, &params, &identity)?;
+    let thresholds = params.region_thresholds.clone().unwrap_or_default();
+    let margin = params.stack_margin_bytes.unwrap_or(DEFAULT_STACK_MARGIN);
+    let session = Arc::new(Session::default());
+    attach_svd(&session, params.svd_path.as_deref())?;
     if let Some(mut result) = load_cached_result(&app, &cache_key)? {
+        result.meta.svd_path = params.svd_path.clone();
         result.meta.cache = CacheMeta {
             hit: true,
             key: cache_key.clone(),
         };
         if let Some(symbols) = load_cached_symbols(&app, &cache_key)? {
-            if let Ok(mut stored) = state.symbols.lock() {
-                *stored = symbols;
+            if let Ok(mut stored) = session.symbols.lock() {
+                *stored = SymbolIndex::new(symbols);
             }
         }
+        apply_stack_margin(margin, &mut result);
+        apply_region_forecasts(&app, &thresholds, &mut result)?;
+        if let Ok(mut stored) = session.analysis.lock() {
+            *stored = Some(result.clone());
+        }
+        state.commit_session(&cache_key, &params.elf_path, session)?;
         return Ok(result);
     }
 
     let objdump_out = run_command(&toolchain_paths.objdump_path, &["-h", &params.elf_path])?;
     let sections = parse_objdump_sections(&objdump_out);
+    let headers_out = run_command(&toolchain_paths.objdump_path, &["-p", &params.elf_path])?;
+    let segments = parse_program_headers(&headers_out);
 
     let nm_out = run_command(
         &toolchain_paths.nm_path,
         &["-S", "--size-sort", &params.elf_path],
     )?;
     let mut all_symbols = parse_nm_symbols(&nm_out);
+    apply_symbol_sections(&mut all_symbols, &params.elf_path)?;
+    let mapping_out = run_command(&toolchain_paths.nm_path, &["--special-syms", "-n", &params.elf_path])?;
+    let code_data = apply_code_data_split(&mut all_symbols, &parse_mapping_symbols(&mapping_out));
+    if let Some(map_path) = params.map_path.as_deref() {
+        apply_symbol_objects(&mut all_symbols, map_path)?;
+    }
     let mut symbols = all_symbols.clone();
     symbols.sort_by(|a, b| b.size.cmp(&a.size));
     symbols.truncate(50);
 
-    let totals = compute_section_totals(&sections);
-    let (top_objects, top_libraries, top_sections, map_tree, memory_regions) =
+    let totals = compute_section_totals(&sections, &classifier);
+    let (top_objects, top_libraries, top_sections, map_tree, mut memory_regions) =
         if let Some(map_path) = params.map_path.as_ref() {
             parse_map_contributions(map_path)?
     } else {
         (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new())
     };
+    if memory_regions.is_empty() {
+        if let Some(script_path) = params.linker_script_path.as_deref().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+            memory_regions = parse_linker_script_file(script_path)?;
+            apply_section_usage(&mut memory_regions, &sections);
+        }
+    }
+    apply_segment_usage(&mut memory_regions, &segments);
     let totals = apply_region_totals(totals, &memory_regions);
     let strings_count = count_strings_lines(&toolchain_paths.strings_path, &params.elf_path).ok();
-    let findings = compute_findings(&mut all_symbols, &sections, strings_count);
-    if let Ok(mut stored) = state.symbols.lock() {
-        *stored = all_symbols.clone();
+    let mut findings = compute_findings(&mut all_symbols, &sections, strings_count);
+    let nm_full_out = run_command(&toolchain_paths.nm_path, &["-S", &params.elf_path])?;
+    let linkage = build_linkage_report(
+        &toolchain_paths.nm_path,
+        &nm_full_out,
+        &params.elf_path,
+        params.map_path.as_deref(),
+    )?;
+    findings.extend(compute_linkage_findings(&linkage));
+    let build_info = build_build_info(
+        &toolchain_paths.objdump_path,
+        &params.elf_path,
+        params.map_path.as_deref(),
+        &sections,
+    )?;
+    findings.extend(compute_build_info_findings(&build_info));
+    let stack_heap = build_stack_heap_report(&nm_full_out);
+    let layout = build_address_layout(&sections, &memory_regions);
+    findings.extend(compute_layout_findings(&layout));
+    findings.extend(compute_code_data_findings(&code_data));
+    if let Ok(mut stored) = session.symbols.lock() {
+        *stored = SymbolIndex::new(all_symbols.clone());
     }
 
-    let result = AnalysisResult {
+    let mut result = AnalysisResult {
         meta: AnalysisMeta {
             elf_path: params.elf_path,
             map_path: params.map_path,
+            linker_script_path: params.linker_script_path,
+            svd_path: params.svd_path,
+            build_info,
             toolchain: toolchain_paths,
             cache: CacheMeta {
                 hit: false,
@@ -203,13 +390,110 @@ pub fn analyze_firmware(
             findings,
         },
         sections,
+        segments,
+        layout,
+        linkage,
+        stack_heap,
+        forecasts: Vec::new(),
+        code_data,
+        identity,
     };
 
     store_cached_result(&app, &cache_key, &result)?;
     store_cached_symbols(&app, &cache_key, &all_symbols)?;
+    apply_stack_margin(margin, &mut result);
+    apply_region_forecasts(&app, &thresholds, &mut result)?;
+    if let Ok(mut stored) = session.analysis.lock() {
+        *stored = Some(result.clone());
+    }
+    state.commit_session(&cache_key, &result.meta.elf_path, session)?;
     Ok(result)
 }
 
+#[tauri::command]
+pub fn list_sessions(state: tauri::State<'_, AppState>) -> Result<Vec<SessionInfo>, String> {
+    let sessions = state.sessions.lock().map_err(|_| "Failed to read sessions.".to_string())?;
+    let mut infos: Vec<SessionInfo> = sessions
+        .iter()
+        .filter_map(|(id, session)| {
+            let analysis = session.analysis.lock().ok()?;
+            let analysis = analysis.as_ref()?;
+            Some(SessionInfo {
+                id: id.clone(),
+                elf_path: analysis.meta.elf_path.clone(),
+                map_path: analysis.meta.map_path.clone(),
+                build_id: analysis.identity.build_id.clone(),
+                symbol_count: session.symbols.lock().map(|symbols| symbols.len()).unwrap_or(0),
+            })
+        })
+        .collect();
+    infos.sort_by(|a, b| a.elf_path.cmp(&b.elf_path).then_with(|| a.id.cmp(&b.id)));
+    Ok(infos)
+}
+
+#[tauri::command]
+pub fn close_session(state: tauri::State<'_, AppState>, session_id: String) -> Result<(), String> {
+    let mut sessions = state.sessions.lock().map_err(|_| "Failed to read sessions.".to_string())?;
+    sessions.remove(session_id.trim());
+    Ok(())
+}
+
+/// The stored analysis of an open session, so the UI can switch to it.
+#[tauri::command]
+pub fn get_session(state: tauri::State<'_, AppState>, session_id: String) -> Result<AnalysisResult, String> {
+    let session = state.session(&session_id)?;
+    let analysis = session.analysis.lock().map_err(|_| "Failed to read session.".to_string())?;
+    analysis
+        .clone()
+        .ok_or_else(|| format!("Session {} has no analysis yet.", session_id.trim()))
+}
+
+/// The SVD only feeds address lookups, so it is parsed per call and kept out of the cache key.
+fn attach_svd(session: &Session, svd_path: Option<&str>) -> Result<(), String> {
+    let device = match svd_path.map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        Some(path) => Some(Arc::new(parse_svd_file(path)?)),
+        None => None,
+    };
+    if let Ok(mut stored) = session.svd.lock() {
+        *stored = device;
+    }
+    Ok(())
+}
+
+fn lookup_peripheral(session: &Session, addr: u64) -> Option<PeripheralMatch> {
+    session.svd.lock().ok()?.as_ref()?.lookup(addr)
+}
+
+/// The stack/heap margin is a per-call setting, so its finding is added outside the cache.
+fn apply_stack_margin(margin: u64, result: &mut AnalysisResult) {
+    result.stack_heap.margin_bytes = margin;
+    result
+        .summary
+        .findings
+        .extend(compute_stack_heap_findings(&result.stack_heap));
+}
+
+/// Threshold and growth findings depend on per-call settings and on the build history,
+/// so they are attached after the cached result is loaded or stored.
+fn apply_region_forecasts(
+    app: &tauri::AppHandle,
+    thresholds: &RegionThresholds,
+    result: &mut AnalysisResult,
+) -> Result<(), String> {
+    let history = record_usage_snapshot(
+        app,
+        &result.meta.elf_path,
+        result.identity.build_id.as_deref().unwrap_or(&result.identity.image_sha256),
+        &result.summary.memory_regions,
+    )?;
+    result.forecasts = compute_region_forecasts(&result.summary.memory_regions, &history);
+    result
+        .summary
+        .findings
+        .extend(compute_region_findings(&result.forecasts, thresholds));
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SymbolQuery {
     pub query: Option<String>,
@@ -218,7 +502,12 @@ pub struct SymbolQuery {
     pub sort: Option<String>,
     pub order: Option<String>,
     pub section: Option<String>,
+    #[serde(default)]
+    pub class: Option<String>,
     pub kind: Option<String>,
+    /// Treat `query` as a fuzzy pattern and rank by match quality instead of `sort`.
+    #[serde(default)]
+    pub fuzzy: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -231,17 +520,27 @@ pub struct PagedSymbols {
 pub struct FacetItem {
     pub value: String,
     pub count: usize,
+    #[serde(default)]
+    pub size: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SymbolFacets {
+    /// Real ELF sections; `classes` groups by the coarse nm class.
     pub sections: Vec<FacetItem>,
+    #[serde(default)]
+    pub classes: Vec<FacetItem>,
     pub kinds: Vec<FacetItem>,
 }
 
 #[tauri::command]
-pub fn list_symbols(state: tauri::State<'_, AppState>, query: SymbolQuery) -> Result<PagedSymbols, String> {
-    let data = state.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+pub fn list_symbols(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    query: SymbolQuery,
+) -> Result<PagedSymbols, String> {
+    let session = state.session(&session_id)?;
+    let data = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
     if data.is_empty() {
         return Ok(PagedSymbols {
             total: 0,
@@ -249,134 +548,149 @@ pub fn list_symbols(state: tauri::State<'_, AppState>, query: SymbolQuery) -> Re
         });
     }
 
-    let mut items: Vec<SymbolInfo> = data
-        .iter()
-        .filter(|symbol| match query.query.as_ref() {
-            Some(q) if !q.trim().is_empty() => {
-                let needle = q.trim().to_ascii_lowercase();
-                symbol.name.to_ascii_lowercase().contains(&needle)
-            }
-            _ => true,
-        })
-        .filter(|symbol| match query.section.as_ref() {
-            Some(section) if !section.trim().is_empty() => {
-                symbol.section_guess.eq_ignore_ascii_case(section.trim())
-            }
-            _ => true,
-        })
-        .filter(|symbol| match query.kind.as_ref() {
-            Some(kind) if !kind.trim().is_empty() => symbol.kind.eq_ignore_ascii_case(kind.trim()),
-            _ => true,
-        })
-        .cloned()
-        .collect();
-
-    let order = query.order.as_deref().unwrap_or("desc");
-    match query.sort.as_deref() {
-        Some("name") => {
-            items.sort_by(|a, b| a.name.cmp(&b.name));
-        }
-        _ => {
-            items.sort_by(|a, b| a.size.cmp(&b.size));
-        }
-    }
-    if order == "desc" {
-        items.reverse();
-    }
+    let text = query.query.as_deref().map(str::trim).filter(|q| !q.is_empty());
+    let section = query.section.as_deref().map(str::trim).filter(|s| !s.is_empty());
+    let class = query.class.as_deref().map(str::trim).filter(|c| !c.is_empty());
+    let kind = query.kind.as_deref().map(str::trim).filter(|k| !k.is_empty());
+    let facet_match = |symbol: &SymbolInfo| {
+        section.map(|s| symbol.section.as_deref() == Some(s)).unwrap_or(true)
+            && class.map(|c| symbol.section_guess.eq_ignore_ascii_case(c)).unwrap_or(true)
+            && kind.map(|k| symbol.kind.eq_ignore_ascii_case(k)).unwrap_or(true)
+    };
+    let pattern = text.filter(|_| query.fuzzy).map(FuzzyPattern::new).filter(|p| !p.is_empty());
+    let ordered = if let Some(pattern) = pattern {
+        let mut scored: Vec<(i32, u32)> = data
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol)| facet_match(symbol))
+            .filter_map(|(idx, symbol)| Some((pattern.score(&symbol.name)?, idx as u32)))
+            .collect();
+        // Best match first; among equal scores the shorter, then alphabetically first name.
+        scored.sort_by(|a, b| {
+            let (left, right) = (&data[a.1 as usize].name, &data[b.1 as usize].name);
+            b.0.cmp(&a.0).then_with(|| left.len().cmp(&right.len())).then_with(|| left.cmp(right))
+        });
+        scored.into_iter().map(|(_, idx)| idx).collect()
+    } else {
+        let filter = match text {
+            Some(text) => Some(SymbolFilter::parse(text)?),
+            None => None,
+        };
+        let candidates = match filter.as_ref().and_then(|f| f.required_name_substring()) {
+            Some(needle) => data.name_matches(needle),
+            None => data.scan(|_| true),
+        };
+        let matches: Vec<u32> = candidates
+            .into_iter()
+            .filter(|idx| {
+                let symbol = &data[*idx as usize];
+                facet_match(symbol) && filter.as_ref().map(|f| f.matches(symbol)).unwrap_or(true)
+            })
+            .collect();
+        let descending = query.order.as_deref().unwrap_or("desc") == "desc";
+        data.sorted(matches, query.sort.as_deref(), descending)
+    };
 
     let page = query.page.max(1);
     let page_size = query.page_size.max(1);
     let start = (page - 1) * page_size;
-    let end = start + page_size;
-    let total = items.len();
-    let paged = if start >= total {
-        Vec::new()
-    } else {
-        items[start..end.min(total)].to_vec()
-    };
+    let total = ordered.len();
+    let paged = ordered
+        .iter()
+        .skip(start)
+        .take(page_size)
+        .map(|idx| data[*idx as usize].clone())
+        .collect();
 
     Ok(PagedSymbols { total, items: paged })
 }
 
 #[tauri::command]
-pub fn list_symbol_facets(state: tauri::State<'_, AppState>) -> Result<SymbolFacets, String> {
-    let data = state.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
-    if data.is_empty() {
-        return Ok(SymbolFacets {
-            sections: Vec::new(),
-            kinds: Vec::new(),
-        });
-    }
+pub fn list_symbol_facets(state: tauri::State<'_, AppState>, session_id: String) -> Result<SymbolFacets, String> {
+    let session = state.session(&session_id)?;
+    let data = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+    Ok(data.facets().clone())
+}
 
-    let mut section_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
-    let mut kind_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+#[tauri::command]
+pub fn lookup_pc(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    address: String,
+) -> Result<PcLookupResult, String> {
+    let session = state.session(&session_id)?;
+    resolve_pc(&session, address)
+}
 
-    for symbol in data.iter() {
-        if !symbol.section_guess.trim().is_empty() {
-            *section_counts.entry(symbol.section_guess.clone()).or_insert(0) += 1;
-        }
-        if !symbol.kind.trim().is_empty() {
-            *kind_counts.entry(symbol.kind.clone()).or_insert(0) += 1;
-        }
+pub fn resolve_pc(session: &Session, address: String) -> Result<PcLookupResult, String> {
+    let addr_value = parse_pc_address(&address)?;
+    let data = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+    if data.is_empty() {
+        return Err("Symbol cache is empty. Run analysis first.".to_string());
     }
 
-    let mut sections: Vec<FacetItem> = section_counts
-        .into_iter()
-        .map(|(value, count)| FacetItem { value, count })
-        .collect();
-    sections.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    let symbol = find_containing_symbol(&data, addr_value);
+    drop(data);
+    let peripheral = lookup_peripheral(session, addr_value);
 
-    let mut kinds: Vec<FacetItem> = kind_counts
-        .into_iter()
-        .map(|(value, count)| FacetItem { value, count })
-        .collect();
-    kinds.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
-
-    Ok(SymbolFacets { sections, kinds })
+    Ok(PcLookupResult {
+        address,
+        symbol,
+        peripheral,
+    })
 }
 
+/// Resolves a RAM address (watchpoint hit, BFAR value) to the variable and member path
+/// it falls in, falling back to the containing symbol when no debug info covers it.
 #[tauri::command]
-pub fn lookup_pc(state: tauri::State<'_, AppState>, address: String) -> Result<PcLookupResult, String> {
+pub fn lookup_data_address(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    address: String,
+) -> Result<DataLookupResult, String> {
+    let session = state.session(&session_id)?;
     let addr_value = parse_pc_address(&address)?;
-    let data = state.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
-    if data.is_empty() {
-        return Err("Symbol cache is empty. Run analysis first.".to_string());
-    }
-
-    let mut best: Option<(u64, &SymbolInfo)> = None;
-    for symbol in data.iter() {
-        let addr_str = match symbol.addr.as_ref() {
-            Some(value) => value,
-            None => continue,
-        };
-        let start = match parse_hex_str(addr_str) {
-            Some(value) => value,
-            None => continue,
-        };
-        if symbol.size == 0 {
-            continue;
-        }
-        let end = start.saturating_add(symbol.size);
-        if addr_value >= start && addr_value < end {
-            match best {
-                Some((best_start, _)) if best_start >= start => {}
-                _ => best = Some((start, symbol)),
-            }
+    let symbol = {
+        let data = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+        if data.is_empty() {
+            return Err("Symbol cache is empty. Run analysis first.".to_string());
         }
-    }
+        find_containing_symbol(&data, addr_value)
+    };
+    let peripheral = lookup_peripheral(&session, addr_value);
+    let path = dwarf_for_session(&session)
+        .ok()
+        .and_then(|index| index.data_path(addr_value));
+    let (path, type_name, member_offset, padding) = match (path, peripheral.as_ref()) {
+        (Some(path), _) => (Some(path.path), Some(path.type_name), path.offset, path.padding),
+        (None, Some(peripheral)) => (Some(peripheral.path.clone()), None, peripheral.offset, false),
+        (None, None) => (
+            symbol.as_ref().map(|s| format!("{}+0x{:x}", s.name, s.offset)),
+            None,
+            symbol.as_ref().map(|s| s.offset).unwrap_or(0),
+            false,
+        ),
+    };
+    Ok(DataLookupResult {
+        address,
+        symbol,
+        peripheral,
+        path,
+        type_name,
+        member_offset,
+        padding,
+    })
+}
 
-    let symbol = best.map(|(start, symbol)| PcLookupSymbol {
+fn find_containing_symbol(data: &SymbolIndex, addr_value: u64) -> Option<PcLookupSymbol> {
+    data.containing(addr_value).map(|(start, symbol)| PcLookupSymbol {
         name: symbol.name.clone(),
         addr: symbol.addr.clone().unwrap_or_else(|| format!("{:x}", start)),
         size: symbol.size,
         kind: symbol.kind.clone(),
         section_guess: symbol.section_guess.clone(),
+        section: symbol.section.clone(),
         offset: addr_value.saturating_sub(start),
-    });
-
-    Ok(PcLookupResult {
-        address,
-        symbol,
     })
 }
 
@@ -400,10 +714,38 @@ fn validate_inputs(params: &AnalyzeParams) -> Result<(), String> {
             }
         }
     }
+    if let Some(script_path) = params.linker_script_path.as_ref() {
+        let script_path = script_path.trim();
+        if !script_path.is_empty() {
+            let metadata = fs::metadata(script_path)
+                .map_err(|e| format!("Failed to read linker script {}: {}", script_path, e))?;
+            if !metadata.is_file() {
+                return Err("Linker script path must point to a file.".to_string());
+            }
+        }
+    }
+    if let Some(svd_path) = params.svd_path.as_ref() {
+        let svd_path = svd_path.trim();
+        if !svd_path.is_empty() {
+            let metadata = fs::metadata(svd_path).map_err(|e| format!("Failed to read SVD file {}: {}", svd_path, e))?;
+            if !metadata.is_file() {
+                return Err("SVD path must point to a file.".to_string());
+            }
+        }
+    }
+    if let Some(thresholds) = params.region_thresholds.as_ref() {
+        let (warn, critical) = (thresholds.warn_percent, thresholds.critical_percent);
+        if !(0.0..=100.0).contains(&warn) || !(0.0..=100.0).contains(&critical) {
+            return Err("Region usage thresholds must be between 0 and 100 percent.".to_string());
+        }
+        if warn >= critical {
+            return Err("The region warning threshold must be below the critical threshold.".to_string());
+        }
+    }
     Ok(())
 }
 
-fn run_command(program: &str, args: &[&str]) -> Result<String, String> {
+pub fn run_command(program: &str, args: &[&str]) -> Result<String, String> {
     let output = Command::new(program)
         .args(args)
         .output()
@@ -415,25 +757,118 @@ fn run_command(program: &str, args: &[&str]) -> Result<String, String> {
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// Reads a section's raw bytes from the ELF file using the offset reported by `objdump -h`.
+pub fn read_section_bytes(elf_path: &str, section: &SectionInfo) -> Result<Vec<u8>, String> {
+    let offset = section
+        .file_offset
+        .ok_or_else(|| format!("No file offset recorded for section {}.", section.name))?;
+    let mut file = fs::File::open(elf_path).map_err(|e| format!("Failed to open ELF file {}: {}", elf_path, e))?;
+    file.seek(std::io::SeekFrom::Start(offset))
+        .map_err(|e| format!("Failed to read section {}: {}", section.name, e))?;
+    let mut bytes = vec![0u8; section.size as usize];
+    file.read_exact(&mut bytes)
+        .map_err(|e| format!("Failed to read section {}: {}", section.name, e))?;
+    Ok(bytes)
+}
+
 fn parse_objdump_sections(output: &str) -> Vec<SectionInfo> {
-    output
-        .lines()
-        .filter_map(|line| {
-            let trimmed = line.trim();
-            if trimmed.is_empty() || !trimmed.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(false) {
-                return None;
+    let mut sections: Vec<SectionInfo> = Vec::new();
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if !trimmed.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            // objdump prints the section flags on the line following each entry.
+            if let Some(last) = sections.last_mut() {
+                if last.flags.is_empty() && trimmed.chars().all(|c| c.is_ascii_uppercase() || c == ',' || c == ' ' || c == '_') {
+                    last.flags = trimmed.split(',').map(|f| f.trim().to_string()).filter(|f| !f.is_empty()).collect();
+                }
             }
-            let parts: Vec<&str> = trimmed.split_whitespace().collect();
-            if parts.len() < 4 {
-                return None;
+            continue;
+        }
+        let parts: Vec<&str> = trimmed.split_whitespace().collect();
+        if parts.len() < 4 {
+            continue;
+        }
+        let name = parts[1].to_string();
+        let size = match u64::from_str_radix(parts[2], 16) {
+            Ok(size) => size,
+            Err(_) => continue,
+        };
+        let vma = parts.get(3).map(|v| v.to_string());
+        let lma = parts.get(4).map(|v| v.to_string());
+        let align = parts
+            .get(6)
+            .and_then(|value| value.strip_prefix("2**"))
+            .and_then(|exp| exp.parse::<u32>().ok())
+            .and_then(|exp| 1u64.checked_shl(exp))
+            .unwrap_or(1);
+        let file_offset = parts.get(5).and_then(|value| u64::from_str_radix(value, 16).ok());
+        sections.push(SectionInfo {
+            name,
+            size,
+            vma,
+            lma,
+            align,
+            flags: Vec::new(),
+            file_offset,
+        });
+    }
+    sections
+}
+
+fn parse_program_headers(output: &str) -> Vec<ProgramSegment> {
+    let mut segments: Vec<ProgramSegment> = Vec::new();
+    let mut in_headers = false;
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("Program Header:") {
+            in_headers = true;
+            continue;
+        }
+        if !in_headers {
+            continue;
+        }
+        if trimmed.is_empty() {
+            if segments.is_empty() {
+                continue;
             }
-            let name = parts.get(1)?.to_string();
-            let size = u64::from_str_radix(parts.get(2)?, 16).ok()?;
-            let vma = parts.get(3).map(|v| v.to_string());
-            let lma = parts.get(4).map(|v| v.to_string());
-            Some(SectionInfo { name, size, vma, lma })
-        })
-        .collect()
+            break;
+        }
+        let parts: Vec<&str> = trimmed.split_whitespace().collect();
+        let field = |key: &str| {
+            parts
+                .iter()
+                .position(|part| *part == key)
+                .and_then(|idx| parts.get(idx + 1))
+                .copied()
+        };
+        if parts.get(1) == Some(&"off") {
+            let align = field("align")
+                .and_then(|value| value.strip_prefix("2**"))
+                .and_then(|exp| exp.parse::<u32>().ok())
+                .and_then(|exp| 1u64.checked_shl(exp))
+                .unwrap_or(0);
+            segments.push(ProgramSegment {
+                kind: parts[0].to_string(),
+                offset: field("off").map(parse_hex_or_dec).unwrap_or(0),
+                vaddr: field("vaddr").unwrap_or("0x0").to_string(),
+                paddr: field("paddr").unwrap_or("0x0").to_string(),
+                filesz: 0,
+                memsz: 0,
+                flags: String::new(),
+                align,
+            });
+        } else if parts.first() == Some(&"filesz") {
+            if let Some(last) = segments.last_mut() {
+                last.filesz = field("filesz").map(parse_hex_or_dec).unwrap_or(0);
+                last.memsz = field("memsz").map(parse_hex_or_dec).unwrap_or(0);
+                last.flags = field("flags").unwrap_or("").to_string();
+            }
+        }
+    }
+    segments
 }
 
 fn parse_nm_symbols(output: &str) -> Vec<SymbolInfo> {
@@ -466,12 +901,17 @@ fn parse_nm_symbols(output: &str) -> Vec<SymbolInfo> {
             addr,
             kind,
             section_guess,
+            section: None,
+            section_index: None,
+            data_bytes: None,
+            object: None,
+            library: None,
         });
     }
     symbols
 }
 
-fn compute_section_totals(sections: &[SectionInfo]) -> SectionTotals {
+fn compute_section_totals(sections: &[SectionInfo], classifier: &SectionClassifier) -> SectionTotals {
     let mut totals = SectionTotals {
         flash_bytes: 0,
         ram_bytes: 0,
@@ -483,19 +923,23 @@ fn compute_section_totals(sections: &[SectionInfo]) -> SectionTotals {
         ram_region_bytes: None,
     };
     for section in sections {
-        let name = section.name.as_str();
-        if name == ".text" || name.starts_with(".text.") {
-            totals.text_bytes += section.size;
-        } else if name == ".rodata" || name.starts_with(".rodata.") {
-            totals.rodata_bytes += section.size;
-        } else if name == ".data" || name.starts_with(".data.") {
-            totals.data_bytes += section.size;
-        } else if name == ".bss" || name.starts_with(".bss.") {
-            totals.bss_bytes += section.size;
-        }
-    }
-    totals.flash_bytes = totals.text_bytes + totals.rodata_bytes + totals.data_bytes;
-    totals.ram_bytes = totals.data_bytes + totals.bss_bytes;
+        let classification = match classifier.classify(&section.name) {
+            Some(classification) => classification,
+            None => continue,
+        };
+        match classification.class {
+            SectionClass::Text => totals.text_bytes += section.size,
+            SectionClass::Rodata => totals.rodata_bytes += section.size,
+            SectionClass::Data => totals.data_bytes += section.size,
+            SectionClass::Bss => totals.bss_bytes += section.size,
+        }
+        if classification.flash {
+            totals.flash_bytes += section.size;
+        }
+        if classification.ram {
+            totals.ram_bytes += section.size;
+        }
+    }
     totals
 }
 
@@ -510,7 +954,7 @@ fn guess_section(kind: &str) -> String {
     .to_string()
 }
 
-fn is_linker_symbol_name(name: &str) -> bool {
+pub fn is_linker_symbol_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     if !lower.starts_with('_') {
         return false;
@@ -566,6 +1010,7 @@ fn parse_map_contributions(
         if region.used.is_none() {
             if let Some(used) = region_used.get(&region.name.to_ascii_lowercase()) {
                 region.used = Some(*used);
+                region.usage_source = Some("map-scan".to_string());
             }
         }
         if region.padding_bytes.is_none() {
@@ -629,6 +1074,73 @@ fn parse_map_contributions(
     Ok((top_objects, top_libraries, top_sections, map_tree, memory_regions))
 }
 
+/// Input sections of the memory map as (address, size, input file), including entries whose
+/// long section name pushes the address onto the next line.
+fn parse_map_input_sections(contents: &str) -> Vec<(u64, u64, String)> {
+    let mut placements = Vec::new();
+    let mut in_map = false;
+    let mut pending_section = false;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("Linker script and memory map") {
+            in_map = true;
+            continue;
+        }
+        if !in_map || trimmed.is_empty() {
+            continue;
+        }
+        // Input sections are indented by one space; output sections start in column 0.
+        let parts: Vec<&str> = trimmed.split_whitespace().collect();
+        let fields = if line.starts_with(" .") || line.starts_with(" COMMON") {
+            if parts.len() < 4 {
+                pending_section = parts.len() == 1;
+                continue;
+            }
+            &parts[1..]
+        } else if pending_section && parts.len() >= 3 && parts[0].starts_with("0x") {
+            &parts[..]
+        } else {
+            pending_section = false;
+            continue;
+        };
+        pending_section = false;
+        let addr = parse_hex_or_dec(fields[0]);
+        let size = parse_hex_or_dec(fields[1]);
+        let file = fields[2..].join(" ");
+        if size > 0 && fields[1].starts_with("0x") && (file.contains(".o") || file.contains(".a")) {
+            placements.push((addr, size, file));
+        }
+    }
+    placements.sort_by_key(|(addr, _, _)| *addr);
+    placements
+}
+
+fn apply_symbol_objects(symbols: &mut [SymbolInfo], map_path: &str) -> Result<(), String> {
+    let contents =
+        fs::read_to_string(map_path).map_err(|e| format!("Failed to read MAP file {}: {}", map_path, e))?;
+    let placements = parse_map_input_sections(&contents);
+    for symbol in symbols.iter_mut() {
+        let addr = match symbol.addr.as_deref().and_then(parse_hex_str) {
+            Some(addr) => addr & !1,
+            None => continue,
+        };
+        let idx = placements.partition_point(|(start, _, _)| *start <= addr);
+        let placement = idx
+            .checked_sub(1)
+            .map(|idx| &placements[idx])
+            .filter(|(start, size, _)| addr < start + size);
+        if let Some((_, _, file)) = placement {
+            let (library, object) = split_library_object(file);
+            symbol.object = Some(match library.as_ref() {
+                Some(library) => format!("{}({})", library, object),
+                None => object,
+            });
+            symbol.library = library;
+        }
+    }
+    Ok(())
+}
+
 fn top_contributions(map: std::collections::HashMap<String, u64>, limit: usize) -> Vec<ObjectContribution> {
     let mut result: Vec<ObjectContribution> = map
         .into_iter()
@@ -764,6 +1276,7 @@ fn parse_memory_regions(contents: &str) -> Vec<MemoryRegion> {
             if name.to_ascii_lowercase() == "default" && used.unwrap_or(0) == 0 {
                 continue;
             }
+            let usage_source = used.map(|_| "map".to_string());
             regions.push(MemoryRegion {
                 name,
                 origin,
@@ -771,6 +1284,7 @@ fn parse_memory_regions(contents: &str) -> Vec<MemoryRegion> {
                 used,
                 padding_bytes: None,
                 sources: Vec::new(),
+                usage_source,
             });
         }
         if in_section && simple_table && trimmed.is_empty() {
@@ -1103,6 +1617,121 @@ fn parse_region_legacy(parts: &[&str]) -> (String, u64, Option<u64>) {
     (origin, length, None)
 }
 
+/// Fills region usage from ELF section addresses: allocated sections count at their VMA and
+/// loaded sections whose LMA differs (e.g. `.data` init images) also count at their LMA.
+fn apply_section_usage(regions: &mut [MemoryRegion], sections: &[SectionInfo]) {
+    let ranges: Vec<(u64, u64)> = regions
+        .iter()
+        .map(|region| {
+            let start = parse_hex_or_dec(&region.origin);
+            (start, start.saturating_add(region.length))
+        })
+        .collect();
+    let mut used = vec![0u64; regions.len()];
+    let mut max_end = vec![0u64; regions.len()];
+    let mut region_sections: Vec<std::collections::HashMap<String, u64>> = vec![Default::default(); regions.len()];
+
+    for section in sections {
+        if section.size == 0 || !section.flags.iter().any(|f| f == "ALLOC") {
+            continue;
+        }
+        let vma = section.vma.as_deref().and_then(parse_hex_str);
+        let lma = section.lma.as_deref().and_then(parse_hex_str);
+        let mut placements = Vec::new();
+        if let Some(vma) = vma {
+            placements.push(vma);
+        }
+        if let Some(lma) = lma {
+            if Some(lma) != vma && section.flags.iter().any(|f| f == "LOAD") {
+                placements.push(lma);
+            }
+        }
+        for addr in placements {
+            if let Some(idx) = ranges.iter().position(|(start, end)| addr >= *start && addr < *end) {
+                used[idx] += section.size;
+                max_end[idx] = max_end[idx].max(addr.saturating_add(section.size));
+                *region_sections[idx].entry(section.name.clone()).or_insert(0) += section.size;
+            }
+        }
+    }
+
+    for (idx, region) in regions.iter_mut().enumerate() {
+        region.used = Some(used[idx]);
+        region.usage_source = Some("sections".to_string());
+        let span = max_end[idx].saturating_sub(ranges[idx].0);
+        if span > used[idx] {
+            region.padding_bytes = Some(span - used[idx]);
+        }
+        let mut items: Vec<RegionSource> = region_sections[idx]
+            .drain()
+            .map(|(name, size)| RegionSource { name, size })
+            .collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.size));
+        items.truncate(6);
+        region.sources = items;
+    }
+}
+
+/// Fills regions that no MAP or section scan could measure from the PT_LOAD view: file
+/// images count at their physical (load) address and memory images at their virtual address.
+fn apply_segment_usage(regions: &mut [MemoryRegion], segments: &[ProgramSegment]) {
+    let loads: Vec<&ProgramSegment> = segments.iter().filter(|s| s.kind == "LOAD").collect();
+    if loads.is_empty() {
+        return;
+    }
+    let ranges: Vec<(u64, u64)> = regions
+        .iter()
+        .map(|region| {
+            let start = parse_hex_or_dec(&region.origin);
+            (start, start.saturating_add(region.length))
+        })
+        .collect();
+    let find_region = |addr: u64| ranges.iter().position(|(start, end)| addr >= *start && addr < *end);
+
+    let mut used = vec![0u64; regions.len()];
+    let mut max_end = vec![0u64; regions.len()];
+    let mut sources: Vec<Vec<RegionSource>> = vec![Vec::new(); regions.len()];
+    for (index, segment) in loads.iter().enumerate() {
+        let vaddr = parse_hex_or_dec(&segment.vaddr);
+        let paddr = parse_hex_or_dec(&segment.paddr);
+        let label = format!("LOAD[{}] {}", index, segment.flags);
+        let mut placements = Vec::new();
+        if vaddr == paddr {
+            placements.push((vaddr, segment.memsz.max(segment.filesz)));
+        } else {
+            placements.push((paddr, segment.filesz));
+            placements.push((vaddr, segment.memsz));
+        }
+        for (addr, size) in placements {
+            if size == 0 {
+                continue;
+            }
+            if let Some(idx) = find_region(addr) {
+                used[idx] += size;
+                max_end[idx] = max_end[idx].max(addr.saturating_add(size));
+                sources[idx].push(RegionSource {
+                    name: label.clone(),
+                    size,
+                });
+            }
+        }
+    }
+
+    for (idx, region) in regions.iter_mut().enumerate() {
+        if region.used.is_some() {
+            continue;
+        }
+        region.used = Some(used[idx]);
+        region.usage_source = Some("segments".to_string());
+        let span = max_end[idx].saturating_sub(ranges[idx].0);
+        region.padding_bytes = if span > used[idx] { Some(span - used[idx]) } else { None };
+        let mut items = std::mem::take(&mut sources[idx]);
+        items.sort_by_key(|item| std::cmp::Reverse(item.size));
+        items.truncate(6);
+        region.sources = items;
+    }
+}
+
 fn apply_region_totals(mut totals: SectionTotals, regions: &[MemoryRegion]) -> SectionTotals {
     if regions.is_empty() {
         return totals;
@@ -1226,19 +1855,36 @@ fn count_strings_lines(program: &str, elf_path: &str) -> Result<u64, String> {
     Ok(count)
 }
 
-fn build_cache_key(toolchain: &ToolchainPaths, params: &AnalyzeParams) -> Result<String, String> {
-    let cache_version = "v12";
+fn build_cache_key(
+    toolchain: &ToolchainPaths,
+    params: &AnalyzeParams,
+    identity: &FirmwareIdentity,
+) -> Result<String, String> {
+    let cache_version = "v24";
     let elf_hash = hash_file(&params.elf_path)?;
     let map_hash = match params.map_path.as_ref().map(|p| p.trim()).filter(|p| !p.is_empty()) {
         Some(path) => hash_file(path)?,
         None => String::from("none"),
     };
+    let script_hash = match params.linker_script_path.as_ref().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        Some(path) => hash_file(path)?,
+        None => String::from("none"),
+    };
     let tool_sig = format!("{}|{}|{}", toolchain.nm_path, toolchain.objdump_path, toolchain.strings_path);
-    let raw = format!("ver:{}|elf:{}|map:{}|tool:{}", cache_version, elf_hash, map_hash, tool_sig);
+    let rules = rules_signature(params.section_rules.as_deref().unwrap_or_default());
+    let content = format!(
+        "{}:{}",
+        identity.build_id.as_deref().unwrap_or("none"),
+        identity.image_sha256
+    );
+    let raw = format!(
+        "ver:{}|elf:{}|image:{}|map:{}|ld:{}|rules:{}|tool:{}",
+        cache_version, elf_hash, content, map_hash, script_hash, rules, tool_sig
+    );
     Ok(hash_string(&raw))
 }
 
-fn cache_dir(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+pub fn cache_dir(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
     let base_dir = app
         .path()
         .app_config_dir()
@@ -1307,13 +1953,13 @@ fn hash_file(path: &str) -> Result<String, String> {
     Ok(format!("{:x}", hasher.finalize()))
 }
 
-fn hash_string(value: &str) -> String {
+pub fn hash_string(value: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(value.as_bytes());
     format!("{:x}", hasher.finalize())
 }
 
-fn parse_hex_or_dec(value: &str) -> u64 {
+pub fn parse_hex_or_dec(value: &str) -> u64 {
     if let Some(hex) = value.strip_prefix("0x") {
         u64::from_str_radix(hex, 16).unwrap_or(0)
     } else {
@@ -1372,7 +2018,7 @@ fn unit_multiplier(unit: &str) -> Option<f64> {
     }
 }
 
-fn parse_pc_address(value: &str) -> Result<u64, String> {
+pub fn parse_pc_address(value: &str) -> Result<u64, String> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
         return Err("Address is required.".to_string());
@@ -1391,7 +2037,7 @@ fn parse_pc_address(value: &str) -> Result<u64, String> {
     u64::from_str_radix(digits, radix).map_err(|_| "Invalid address.".to_string())
 }
 
-fn parse_hex_str(value: &str) -> Option<u64> {
+pub fn parse_hex_str(value: &str) -> Option<u64> {
     let trimmed = value.trim();
     let digits = trimmed.strip_prefix("0x").unwrap_or(trimmed);
     u64::from_str_radix(digits, 16).ok()
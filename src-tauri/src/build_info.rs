@@ -0,0 +1,410 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+use crate::analyzer::{run_command, Finding, SectionInfo};
+use crate::dwarf::read_producers;
+use crate::linkage::{is_linked_input, map_load_inputs, object_label};
+
+const OBJECT_BATCH_SIZE: usize = 128;
+const MISMATCH_ITEMS: usize = 10;
+
+/// Toolchain provenance: EABI build attributes, `.comment` compiler strings and DWARF
+/// producers for the ELF, plus the attributes of each linked object where available.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BuildInfo {
+    pub attributes: Option<ArmAttributes>,
+    pub compilers: Vec<String>,
+    pub producers: Vec<String>,
+    pub objects: Vec<ObjectBuildInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectBuildInfo {
+    pub object: String,
+    pub attributes: Option<ArmAttributes>,
+    pub compilers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ArmAttributes {
+    pub cpu_name: Option<String>,
+    pub cpu_arch: Option<String>,
+    pub profile: Option<String>,
+    pub fp_arch: Option<String>,
+    /// `hard` passes floats in VFP registers; `softfp` and `soft` use core registers and
+    /// link with each other.
+    pub float_abi: String,
+    pub wchar_size: Option<u64>,
+    pub enum_size: Option<String>,
+}
+
+pub fn build_build_info(
+    objdump_path: &str,
+    elf_path: &str,
+    map_path: Option<&str>,
+    sections: &[SectionInfo],
+) -> Result<BuildInfo, String> {
+    let mut info = BuildInfo::default();
+    // objdump fails when none of the `-j` sections exist, so only ask for present ones.
+    let mut args: Vec<&str> = vec!["-s"];
+    for name in [".ARM.attributes", ".comment"] {
+        if sections.iter().any(|section| section.name == name) {
+            args.extend(["-j", name]);
+        }
+    }
+    if args.len() > 1 {
+        args.push(elf_path);
+        let output = run_command(objdump_path, &args)?;
+        if let Some(dump) = parse_section_dumps(&output, &[]).into_iter().next() {
+            info.attributes = dump.attributes.as_deref().and_then(|bytes| decode_attributes(bytes, dump.big_endian));
+            info.compilers = dump.comment.as_deref().map(parse_comment).unwrap_or_default();
+        }
+    }
+    info.producers = read_producers(elf_path, sections).unwrap_or_default();
+
+    let map_path = match map_path.map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        Some(path) => path,
+        None => return Ok(info),
+    };
+    let contents = fs::read_to_string(map_path).map_err(|e| format!("Failed to read MAP file {}: {}", map_path, e))?;
+    let (inputs, linked) = map_load_inputs(elf_path, map_path, &contents);
+    for batch in inputs.chunks(OBJECT_BATCH_SIZE) {
+        let mut args: Vec<&str> = vec!["-s", "-j", ".ARM.attributes", "-j", ".comment"];
+        args.extend(batch.iter().map(|path| path.as_str()));
+        let output = match run_command(objdump_path, &args) {
+            Ok(output) => output,
+            Err(_) => continue,
+        };
+        for dump in parse_section_dumps(&output, batch) {
+            if !is_linked_input(&dump.object, &linked) {
+                continue;
+            }
+            info.objects.push(ObjectBuildInfo {
+                object: dump.object,
+                attributes: dump.attributes.as_deref().and_then(|bytes| decode_attributes(bytes, dump.big_endian)),
+                compilers: dump.comment.as_deref().map(parse_comment).unwrap_or_default(),
+            });
+        }
+    }
+    Ok(info)
+}
+
+pub fn compute_build_info_findings(info: &BuildInfo) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let objects: Vec<(&str, &ArmAttributes)> = info
+        .objects
+        .iter()
+        .filter_map(|object| Some((object.object.as_str(), object.attributes.as_ref()?)))
+        .collect();
+
+    // soft and softfp are call-compatible; only hard vs. base-standard conflicts.
+    let float_classes: Vec<(&str, String)> = objects
+        .iter()
+        .filter(|(_, attributes)| attributes.float_abi != "compatible")
+        .map(|(object, attributes)| {
+            let class = if attributes.float_abi == "hard" { "hard" } else { "soft/softfp" };
+            (*object, class.to_string())
+        })
+        .collect();
+    if let Some(finding) = mismatch_finding("FLOAT_ABI_MISMATCH", &float_classes) {
+        findings.push(finding);
+    }
+
+    let enum_classes: Vec<(&str, String)> = objects
+        .iter()
+        .filter_map(|(object, attributes)| {
+            let class = match attributes.enum_size.as_deref()? {
+                "short" => "short",
+                "int" | "int (ABI visible)" => "int",
+                _ => return None,
+            };
+            Some((*object, class.to_string()))
+        })
+        .collect();
+    if let Some(finding) = mismatch_finding("ENUM_SIZE_MISMATCH", &enum_classes) {
+        findings.push(finding);
+    }
+    findings
+}
+
+/// Reports the objects outside the majority setting.
+fn mismatch_finding(id: &str, classes: &[(&str, String)]) -> Option<Finding> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (_, class) in classes {
+        *counts.entry(class.as_str()).or_insert(0) += 1;
+    }
+    if counts.len() < 2 {
+        return None;
+    }
+    let majority = counts
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(class, _)| *class)?;
+    let outliers: Vec<String> = classes
+        .iter()
+        .filter(|(_, class)| class != majority)
+        .map(|(object, class)| format!("{} ({})", object, class))
+        .collect();
+    Some(Finding {
+        id: id.to_string(),
+        severity: "warn".to_string(),
+        value: outliers.len() as u64,
+        items: std::iter::once(format!("majority: {}", majority))
+            .chain(outliers.into_iter().take(MISMATCH_ITEMS))
+            .collect(),
+    })
+}
+
+struct SectionDump {
+    object: String,
+    big_endian: bool,
+    attributes: Option<Vec<u8>>,
+    comment: Option<Vec<u8>>,
+}
+
+/// Splits `objdump -s` output into per-file hex dumps. Archive members are labelled
+/// `lib.a(member.o)` to match the linkage report; a file named exactly like one of
+/// `inputs` ends the preceding archive.
+fn parse_section_dumps(output: &str, inputs: &[String]) -> Vec<SectionDump> {
+    let mut dumps: Vec<SectionDump> = Vec::new();
+    let mut archive: Option<String> = None;
+    let mut current: Option<&'static str> = None;
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix("In archive ") {
+            archive = Some(path.trim_end_matches(':').to_string());
+            continue;
+        }
+        if let Some((file, format)) = line.split_once(":     file format ") {
+            if inputs.iter().any(|input| input == file.trim()) {
+                archive = None;
+            }
+            let object = match archive.as_deref() {
+                Some(archive) => object_label(archive, Some(file.trim())),
+                None => object_label(file.trim(), None),
+            };
+            dumps.push(SectionDump {
+                object,
+                big_endian: format.contains("big"),
+                attributes: None,
+                comment: None,
+            });
+            current = None;
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("Contents of section ") {
+            current = match name.trim_end_matches(':') {
+                ".ARM.attributes" => Some("attributes"),
+                ".comment" => Some("comment"),
+                _ => None,
+            };
+            continue;
+        }
+        let (dump, section) = match (dumps.last_mut(), current) {
+            (Some(dump), Some(section)) => (dump, section),
+            _ => continue,
+        };
+        let bytes = match parse_hex_dump_line(line) {
+            Some(bytes) => bytes,
+            None => continue,
+        };
+        let target = if section == "attributes" { &mut dump.attributes } else { &mut dump.comment };
+        target.get_or_insert_with(Vec::new).extend(bytes);
+    }
+    dumps
+}
+
+/// ` 0000 41300000 00616561 62690001 26000000  A0...aeabi..&...`: the hex area is four
+/// 8-digit groups after the offset, followed by the ASCII column.
+fn parse_hex_dump_line(line: &str) -> Option<Vec<u8>> {
+    let rest = line.strip_prefix(' ')?;
+    let (offset, rest) = rest.split_once(' ')?;
+    if offset.is_empty() || !offset.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let hex: String = rest
+        .chars()
+        .take(35)
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if hex.len() % 2 != 0 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).ok())
+        .collect()
+}
+
+fn parse_comment(bytes: &[u8]) -> Vec<String> {
+    let mut compilers: Vec<String> = Vec::new();
+    for part in bytes.split(|b| *b == 0) {
+        let text = String::from_utf8_lossy(part).trim().to_string();
+        if !text.is_empty() && !compilers.contains(&text) {
+            compilers.push(text);
+        }
+    }
+    compilers
+}
+
+/// Decodes the `aeabi` subsection of `.ARM.attributes` (ARM IHI 0045).
+fn decode_attributes(bytes: &[u8], big_endian: bool) -> Option<ArmAttributes> {
+    if bytes.first() != Some(&b'A') {
+        return None;
+    }
+    let read_u32 = |at: usize| -> Option<usize> {
+        let word: [u8; 4] = bytes.get(at..at + 4)?.try_into().ok()?;
+        Some(if big_endian { u32::from_be_bytes(word) } else { u32::from_le_bytes(word) } as usize)
+    };
+    let mut values: HashMap<u64, u64> = HashMap::new();
+    let mut strings: HashMap<u64, String> = HashMap::new();
+    let mut pos = 1;
+    while pos < bytes.len() {
+        let length = read_u32(pos)?;
+        let end = (pos + length).min(bytes.len());
+        // A truncated or corrupt length would otherwise slice out of bounds.
+        if end < pos + 4 {
+            return None;
+        }
+        let vendor_end = pos + 4 + bytes[pos + 4..end].iter().position(|b| *b == 0)?;
+        let vendor = &bytes[pos + 4..vendor_end];
+        if vendor == b"aeabi" {
+            let mut sub = vendor_end + 1;
+            while sub + 5 <= end {
+                let tag = bytes[sub];
+                let sub_end = (sub + read_u32(sub + 1)?).min(end);
+                if sub_end < sub + 5 {
+                    return None;
+                }
+                // Only file-scope attributes (tag 1) describe the whole object.
+                if tag == 1 {
+                    parse_attribute_list(&bytes[sub + 5..sub_end], &mut values, &mut strings);
+                }
+                sub = sub_end;
+            }
+        }
+        pos += length;
+    }
+
+    let fp_arch = values.get(&10).copied().unwrap_or(0);
+    let float_abi = match values.get(&28).copied().unwrap_or(0) {
+        1 => "hard",
+        3 => "compatible",
+        _ if fp_arch > 0 => "softfp",
+        _ => "soft",
+    };
+    Some(ArmAttributes {
+        cpu_name: strings.get(&5).cloned(),
+        cpu_arch: values.get(&6).map(|v| cpu_arch_name(*v)),
+        profile: values
+            .get(&7)
+            .filter(|v| **v != 0)
+            .map(|v| (*v as u8 as char).to_string()),
+        fp_arch: values.get(&10).filter(|v| **v != 0).map(|v| fp_arch_name(*v)),
+        float_abi: float_abi.to_string(),
+        wchar_size: values.get(&18).copied().filter(|v| *v != 0),
+        enum_size: values.get(&26).and_then(|v| match v {
+            1 => Some("short".to_string()),
+            2 => Some("int".to_string()),
+            3 => Some("int (ABI visible)".to_string()),
+            _ => None,
+        }),
+    })
+}
+
+/// Tags 4, 5 and 67 and odd tags above 32 are NUL-terminated strings; tag 32 is a
+/// ULEB128 followed by a string; everything else is a ULEB128.
+fn parse_attribute_list(bytes: &[u8], values: &mut HashMap<u64, u64>, strings: &mut HashMap<u64, String>) {
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let tag = match read_uleb128(bytes, &mut pos) {
+            Some(tag) => tag,
+            None => return,
+        };
+        let is_string = tag == 4 || tag == 5 || tag == 67 || (tag > 32 && tag % 2 == 1);
+        if tag == 32 {
+            if read_uleb128(bytes, &mut pos).is_none() {
+                return;
+            }
+            read_cstr(bytes, &mut pos);
+        } else if is_string {
+            let value = read_cstr(bytes, &mut pos);
+            strings.insert(tag, value);
+        } else {
+            match read_uleb128(bytes, &mut pos) {
+                Some(value) => {
+                    values.insert(tag, value);
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+fn read_uleb128(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*pos)?;
+        *pos += 1;
+        if shift < 64 {
+            value |= ((byte & 0x7f) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+}
+
+fn read_cstr(bytes: &[u8], pos: &mut usize) -> String {
+    let start = *pos;
+    let end = bytes[start..].iter().position(|b| *b == 0).map(|idx| start + idx).unwrap_or(bytes.len());
+    *pos = (end + 1).min(bytes.len());
+    String::from_utf8_lossy(&bytes[start..end]).to_string()
+}
+
+fn cpu_arch_name(value: u64) -> String {
+    let name = match value {
+        0 => "pre-v4",
+        1 => "v4",
+        2 => "v4T",
+        3 => "v5T",
+        4 => "v5TE",
+        5 => "v5TEJ",
+        6 => "v6",
+        7 => "v6KZ",
+        8 => "v6T2",
+        9 => "v6K",
+        10 => "v7",
+        11 => "v6-M",
+        12 => "v6S-M",
+        13 => "v7E-M",
+        14 => "v8-A",
+        15 => "v8-R",
+        16 => "v8-M.baseline",
+        17 => "v8-M.mainline",
+        18 => "v8.1-A",
+        19 => "v8.2-A",
+        20 => "v8.3-A",
+        21 => "v8.1-M.mainline",
+        22 => "v9-A",
+        _ => return format!("arch {}", value),
+    };
+    name.to_string()
+}
+
+fn fp_arch_name(value: u64) -> String {
+    let name = match value {
+        1 => "VFPv1",
+        2 => "VFPv2",
+        3 => "VFPv3",
+        4 => "VFPv3-D16",
+        5 => "VFPv4",
+        6 => "VFPv4-D16",
+        7 => "FP-ARMv8",
+        8 => "FPv5-D16",
+        _ => return format!("fp {}", value),
+    };
+    name.to_string()
+}
@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::analyzer::{parse_hex_str, read_section_bytes, run_command, AppState, SectionInfo, SymbolInfo};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Disassembly {
+    pub name: String,
+    pub addr: String,
+    pub size: u64,
+    pub instructions: Vec<Instruction>,
+    pub calls: Vec<String>,
+}
+
+/// One line of `objdump -d`. `target` is the symbol a branch or address operand resolves
+/// to; `literal` is set for PC-relative loads from a literal pool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Instruction {
+    pub addr: String,
+    pub bytes: String,
+    pub mnemonic: String,
+    pub operands: String,
+    pub target: Option<String>,
+    pub literal: Option<LiteralLoad>,
+    pub is_data: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiteralLoad {
+    pub pool_addr: String,
+    pub value: Option<String>,
+    pub symbol: Option<String>,
+}
+
+#[tauri::command]
+pub fn disassemble_symbol(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    name: String,
+) -> Result<Disassembly, String> {
+    let session = state.session(&session_id)?;
+    let (elf_path, objdump_path, sections, big_endian) = {
+        let analysis = session.analysis.lock().map_err(|_| "Failed to read analysis state.".to_string())?;
+        let analysis = analysis
+            .as_ref()
+            .ok_or_else(|| "No analysis loaded. Run analysis first.".to_string())?;
+        (
+            analysis.meta.elf_path.clone(),
+            analysis.meta.toolchain.objdump_path.clone(),
+            analysis.sections.clone(),
+            analysis.identity.endianness == "big",
+        )
+    };
+    // The symbol table is only held while the target and the resolver are taken from it,
+    // not for the objdump run.
+    let symbols = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+    let symbol = symbols
+        .iter()
+        .filter(|symbol| symbol.name == name.trim() && symbol.size > 0)
+        .max_by_key(|symbol| matches!(symbol.kind.as_str(), "T" | "t" | "W" | "w"))
+        .ok_or_else(|| format!("Symbol {} not found or has no size.", name.trim()))?;
+    // Thumb function symbols carry the interworking bit.
+    let start = symbol
+        .addr
+        .as_deref()
+        .and_then(parse_hex_str)
+        .ok_or_else(|| format!("Symbol {} has no address.", symbol.name))?
+        & !1;
+    let (symbol_name, size) = (symbol.name.clone(), symbol.size);
+    let end = start + size;
+    let resolver = SymbolResolver::new(&symbols);
+    drop(symbols);
+
+    let output = run_command(
+        &objdump_path,
+        &[
+            "-d",
+            &format!("--start-address=0x{:x}", start),
+            &format!("--stop-address=0x{:x}", end),
+            &elf_path,
+        ],
+    )?;
+    let mut instructions = parse_disassembly(&output);
+    let mut words = LiteralWords::new(&elf_path, &sections, big_endian, &instructions);
+    let mut calls: Vec<String> = Vec::new();
+    for instruction in instructions.iter_mut() {
+        if let Some(literal) = instruction.literal.as_mut() {
+            let pool = parse_hex_str(&literal.pool_addr);
+            if let Some(value) = pool.and_then(|pool| words.read(pool)) {
+                literal.value = Some(format!("0x{:08x}", value));
+                literal.symbol = resolver.describe(value as u64);
+            }
+            continue;
+        }
+        if let Some(target) = instruction.target.as_ref() {
+            let base = target.split('+').next().unwrap_or(target);
+            if base != symbol_name && !calls.iter().any(|c| c == base) {
+                calls.push(base.to_string());
+            }
+        }
+    }
+
+    Ok(Disassembly {
+        name: symbol_name,
+        addr: format!("0x{:08x}", start),
+        size,
+        instructions,
+        calls,
+    })
+}
+
+/// Parses `objdump -d` lines of the form `addr:\tbytes\tmnemonic\toperands\t@ comment`.
+pub fn parse_disassembly(output: &str) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    for line in output.lines() {
+        let (addr, rest) = match line.trim_start().split_once(":\t") {
+            Some(split) => split,
+            None => continue,
+        };
+        if addr.is_empty() || !addr.chars().all(|c| c.is_ascii_hexdigit()) {
+            continue;
+        }
+        let mut fields = rest.splitn(3, '\t');
+        let bytes = fields.next().unwrap_or("").trim().to_string();
+        let mut mnemonic = fields.next().unwrap_or("").trim();
+        let mut operands_raw = fields.next().unwrap_or("").trim();
+        // Some targets pad the mnemonic with spaces instead of a tab.
+        if operands_raw.is_empty() {
+            if let Some((head, tail)) = mnemonic.split_once(char::is_whitespace) {
+                mnemonic = head;
+                operands_raw = tail.trim();
+            }
+        }
+        let mnemonic = mnemonic.to_string();
+        if mnemonic.is_empty() {
+            continue;
+        }
+        // ARM comments start with `@` or `;`, RISC-V and x86 with `#`.
+        let (operands, comment) = match operands_raw.find(['@', ';']).or_else(|| operands_raw.find("# ")) {
+            Some(idx) => (operands_raw[..idx].trim(), Some(operands_raw[idx + 1..].trim())),
+            None => (operands_raw, None),
+        };
+        let is_data = mnemonic.starts_with('.');
+        let mut literal = None;
+        let mut target = None;
+        if let Some(comment) = comment {
+            let pool = comment
+                .trim_start_matches('(')
+                .split_whitespace()
+                .next()
+                .and_then(|value| u64::from_str_radix(value.trim_end_matches(')'), 16).ok());
+            if operands.contains("[pc") && mnemonic.starts_with("ldr") {
+                if let Some(pool) = pool {
+                    literal = Some(LiteralLoad {
+                        pool_addr: format!("0x{:08x}", pool),
+                        value: None,
+                        symbol: None,
+                    });
+                }
+            } else {
+                target = angle_symbol(comment);
+            }
+        }
+        if target.is_none() && literal.is_none() && !is_data {
+            target = angle_symbol(operands);
+        }
+        instructions.push(Instruction {
+            addr: format!("0x{:08x}", u64::from_str_radix(addr, 16).unwrap_or(0)),
+            bytes,
+            mnemonic,
+            operands: operands.to_string(),
+            target,
+            literal,
+            is_data,
+        });
+    }
+    instructions
+}
+
+fn angle_symbol(text: &str) -> Option<String> {
+    let start = text.find('<')?;
+    let end = text[start..].find('>')? + start;
+    Some(text[start + 1..end].to_string())
+}
+
+struct SymbolResolver {
+    ranges: Vec<(u64, u64, String)>,
+}
+
+impl SymbolResolver {
+    fn new(symbols: &[SymbolInfo]) -> Self {
+        let mut ranges: Vec<(u64, u64, String)> = symbols
+            .iter()
+            .filter_map(|symbol| {
+                let start = parse_hex_str(symbol.addr.as_deref()?)?;
+                Some((start, symbol.size, symbol.name.clone()))
+            })
+            .collect();
+        ranges.sort();
+        Self { ranges }
+    }
+
+    /// Names the symbol a literal value points into; function pointers may have the
+    /// Thumb bit set.
+    fn describe(&self, value: u64) -> Option<String> {
+        [value, value & !1].iter().find_map(|value| {
+            let idx = self.ranges.partition_point(|(start, _, _)| start <= value);
+            let (start, size, name) = self.ranges.get(idx.checked_sub(1)?)?;
+            if value == start {
+                Some(name.clone())
+            } else if value - start < *size {
+                Some(format!("{}+0x{:x}", name, value - start))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Literal pool words, taken from `.word` lines when the pool sits inside the function
+/// and from the ELF section bytes otherwise.
+struct LiteralWords<'a> {
+    elf_path: &'a str,
+    sections: &'a [SectionInfo],
+    big_endian: bool,
+    known: HashMap<u64, u32>,
+    loaded: HashMap<String, Option<Vec<u8>>>,
+}
+
+impl<'a> LiteralWords<'a> {
+    fn new(elf_path: &'a str, sections: &'a [SectionInfo], big_endian: bool, instructions: &[Instruction]) -> Self {
+        let known = instructions
+            .iter()
+            .filter(|instruction| instruction.mnemonic == ".word")
+            .filter_map(|instruction| {
+                let addr = parse_hex_str(&instruction.addr)?;
+                let value = instruction.operands.trim().trim_start_matches("0x");
+                Some((addr, u32::from_str_radix(value, 16).ok()?))
+            })
+            .collect();
+        Self {
+            elf_path,
+            sections,
+            big_endian,
+            known,
+            loaded: HashMap::new(),
+        }
+    }
+
+    fn read(&mut self, addr: u64) -> Option<u32> {
+        if let Some(value) = self.known.get(&addr) {
+            return Some(*value);
+        }
+        let section = self.sections.iter().find(|section| {
+            let start = section.vma.as_deref().and_then(parse_hex_str).unwrap_or(0);
+            section.flags.iter().any(|flag| flag == "CONTENTS") && addr >= start && addr + 4 <= start + section.size
+        })?;
+        let start = parse_hex_str(section.vma.as_deref()?)?;
+        let bytes = self
+            .loaded
+            .entry(section.name.clone())
+            .or_insert_with(|| read_section_bytes(self.elf_path, section).ok())
+            .as_ref()?;
+        let offset = (addr - start) as usize;
+        let word: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(word)
+        } else {
+            u32::from_le_bytes(word)
+        })
+    }
+}
@@ -0,0 +1,607 @@
+use gimli::{AttributeValue, EndianSlice, RunTimeEndian};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::sync::Arc;
+
+use crate::analyzer::{read_section_bytes, SectionInfo, Session};
+
+const MAX_TYPE_DEPTH: usize = 32;
+
+/// Type and global-variable model extracted from `.debug_info`. Type references are
+/// absolute `.debug_info` offsets so they stay valid across compilation units.
+pub struct DwarfIndex {
+    pub big_endian: bool,
+    pub address_size: u8,
+    pub types: HashMap<u64, TypeEntry>,
+    pub variables: Vec<GlobalVariable>,
+}
+
+pub struct GlobalVariable {
+    pub name: String,
+    pub addr: u64,
+    pub type_ref: Option<u64>,
+    pub external: bool,
+}
+
+pub struct TypeEntry {
+    pub name: Option<String>,
+    pub kind: TypeKind,
+}
+
+pub enum TypeKind {
+    Base { encoding: u8, size: u64 },
+    Struct { keyword: &'static str, size: u64, members: Vec<Member> },
+    Enum { size: u64, enumerators: Vec<(String, i64)> },
+    Pointer { target: Option<u64>, size: u64 },
+    Array { element: Option<u64>, dims: Vec<Option<u64>> },
+    Typedef { target: Option<u64> },
+    Qualified { keyword: &'static str, target: Option<u64> },
+    Subroutine,
+    Unknown,
+}
+
+/// An address resolved to a variable and member path such as `g_ctx.rx_buf[12]`.
+pub struct DataPath {
+    pub path: String,
+    pub type_name: String,
+    pub offset: u64,
+    pub padding: bool,
+}
+
+pub struct Member {
+    pub name: Option<String>,
+    pub offset: u64,
+    pub type_ref: Option<u64>,
+    pub bit_size: Option<u64>,
+    pub bit_offset: Option<u64>,
+}
+
+type Reader<'a> = EndianSlice<'a, RunTimeEndian>;
+
+/// Returns the DWARF index for the session's analysis, building it on first use.
+pub fn dwarf_for_session(session: &Session) -> Result<Arc<DwarfIndex>, String> {
+    let (elf_path, sections) = {
+        let analysis = session.analysis.lock().map_err(|_| "Failed to read analysis state.".to_string())?;
+        let analysis = analysis
+            .as_ref()
+            .ok_or_else(|| "No analysis loaded. Run analysis first.".to_string())?;
+        (analysis.meta.elf_path.clone(), analysis.sections.clone())
+    };
+    let mut cached = session.dwarf.lock().map_err(|_| "Failed to read DWARF cache.".to_string())?;
+    if let Some(index) = cached.as_ref() {
+        return Ok(index.clone());
+    }
+    let index = Arc::new(load_dwarf_index(&elf_path, &sections)?);
+    *cached = Some(index.clone());
+    Ok(index)
+}
+
+pub fn load_dwarf_index(elf_path: &str, sections: &[SectionInfo]) -> Result<DwarfIndex, String> {
+    let (loaded, endian, address_size) = load_debug_sections(elf_path, sections)?;
+    let dwarf = loaded.borrow(|data| EndianSlice::new(data.as_slice(), endian));
+
+    let big_endian = endian == RunTimeEndian::Big;
+    let mut index = DwarfIndex {
+        big_endian,
+        address_size,
+        types: HashMap::new(),
+        variables: Vec::new(),
+    };
+    let mut declarations: HashMap<u64, (Option<String>, Option<u64>, bool)> = HashMap::new();
+    let mut pending_specs: Vec<(u64, u64, bool)> = Vec::new();
+
+    let mut units = dwarf.units();
+    while let Some(header) = units.next().map_err(dwarf_error)? {
+        let unit = dwarf.unit(header).map_err(dwarf_error)?;
+        let mut entries = unit.entries();
+        let mut depth: isize = 0;
+        let mut parents: Vec<(isize, u64)> = Vec::new();
+        while let Some((delta, entry)) = entries.next_dfs().map_err(dwarf_error)? {
+            depth += delta;
+            while parents.last().map(|(d, _)| *d >= depth).unwrap_or(false) {
+                parents.pop();
+            }
+            let offset = match entry.offset().to_debug_info_offset(&unit.header) {
+                Some(offset) => offset.0 as u64,
+                None => continue,
+            };
+            let parent = parents.last().map(|(_, offset)| *offset);
+            parents.push((depth, offset));
+
+            let name = attr_string(&dwarf, &unit, entry, gimli::DW_AT_name);
+            let type_ref = attr_ref(&unit, entry, gimli::DW_AT_type);
+            let byte_size = attr_udata(entry, gimli::DW_AT_byte_size);
+
+            match entry.tag() {
+                gimli::DW_TAG_base_type => {
+                    let encoding = match entry.attr_value(gimli::DW_AT_encoding).ok().flatten() {
+                        Some(AttributeValue::Encoding(encoding)) => encoding.0,
+                        _ => 0,
+                    };
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Base {
+                                encoding,
+                                size: byte_size.unwrap_or(0),
+                            },
+                        },
+                    );
+                }
+                gimli::DW_TAG_structure_type | gimli::DW_TAG_union_type | gimli::DW_TAG_class_type => {
+                    let keyword = match entry.tag() {
+                        gimli::DW_TAG_union_type => "union",
+                        gimli::DW_TAG_class_type => "class",
+                        _ => "struct",
+                    };
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Struct {
+                                keyword,
+                                size: byte_size.unwrap_or(0),
+                                members: Vec::new(),
+                            },
+                        },
+                    );
+                }
+                gimli::DW_TAG_member | gimli::DW_TAG_inheritance => {
+                    if let Some(TypeEntry {
+                        kind: TypeKind::Struct { members, .. },
+                        ..
+                    }) = parent.and_then(|p| index.types.get_mut(&p))
+                    {
+                        if entry.attr_value(gimli::DW_AT_external).ok().flatten().is_some() {
+                            // C++ static data members live elsewhere.
+                            declarations.insert(offset, (name, type_ref, true));
+                            continue;
+                        }
+                        let name = if entry.tag() == gimli::DW_TAG_inheritance {
+                            Some(format!("<base {}>", type_ref.map(|r| format!("0x{:x}", r)).unwrap_or_default()))
+                        } else {
+                            name
+                        };
+                        members.push(Member {
+                            name,
+                            offset: member_offset(entry).unwrap_or(0),
+                            type_ref,
+                            bit_size: attr_udata(entry, gimli::DW_AT_bit_size),
+                            bit_offset: attr_udata(entry, gimli::DW_AT_data_bit_offset)
+                                .or_else(|| legacy_bit_offset(entry, big_endian)),
+                        });
+                    }
+                }
+                gimli::DW_TAG_enumeration_type => {
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Enum {
+                                size: byte_size.unwrap_or(4),
+                                enumerators: Vec::new(),
+                            },
+                        },
+                    );
+                }
+                gimli::DW_TAG_enumerator => {
+                    if let Some(TypeEntry {
+                        kind: TypeKind::Enum { enumerators, .. },
+                        ..
+                    }) = parent.and_then(|p| index.types.get_mut(&p))
+                    {
+                        let value = entry
+                            .attr(gimli::DW_AT_const_value)
+                            .ok()
+                            .flatten()
+                            .and_then(|attr| attr.sdata_value().or_else(|| attr.udata_value().map(|v| v as i64)))
+                            .unwrap_or(0);
+                        enumerators.push((name.unwrap_or_default(), value));
+                    }
+                }
+                gimli::DW_TAG_pointer_type | gimli::DW_TAG_reference_type | gimli::DW_TAG_rvalue_reference_type => {
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Pointer {
+                                target: type_ref,
+                                size: byte_size.unwrap_or(index.address_size as u64),
+                            },
+                        },
+                    );
+                }
+                gimli::DW_TAG_array_type => {
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Array {
+                                element: type_ref,
+                                dims: Vec::new(),
+                            },
+                        },
+                    );
+                }
+                gimli::DW_TAG_subrange_type => {
+                    if let Some(TypeEntry {
+                        kind: TypeKind::Array { dims, .. },
+                        ..
+                    }) = parent.and_then(|p| index.types.get_mut(&p))
+                    {
+                        let count = attr_udata(entry, gimli::DW_AT_count).or_else(|| {
+                            let upper = entry.attr(gimli::DW_AT_upper_bound).ok().flatten()?;
+                            let upper = upper.sdata_value().or_else(|| upper.udata_value().map(|v| v as i64))?;
+                            let lower = attr_udata(entry, gimli::DW_AT_lower_bound).unwrap_or(0) as i64;
+                            u64::try_from(upper - lower + 1).ok()
+                        });
+                        dims.push(count);
+                    }
+                }
+                gimli::DW_TAG_typedef => {
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Typedef { target: type_ref },
+                        },
+                    );
+                }
+                gimli::DW_TAG_const_type | gimli::DW_TAG_volatile_type | gimli::DW_TAG_restrict_type | gimli::DW_TAG_atomic_type => {
+                    let keyword = match entry.tag() {
+                        gimli::DW_TAG_const_type => "const",
+                        gimli::DW_TAG_volatile_type => "volatile",
+                        gimli::DW_TAG_restrict_type => "restrict",
+                        _ => "_Atomic",
+                    };
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Qualified { keyword, target: type_ref },
+                        },
+                    );
+                }
+                gimli::DW_TAG_subroutine_type => {
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Subroutine,
+                        },
+                    );
+                }
+                gimli::DW_TAG_unspecified_type => {
+                    index.types.insert(
+                        offset,
+                        TypeEntry {
+                            name,
+                            kind: TypeKind::Unknown,
+                        },
+                    );
+                }
+                gimli::DW_TAG_variable => {
+                    let external = entry.attr_value(gimli::DW_AT_external).ok().flatten().is_some();
+                    let addr = variable_address(&dwarf, &unit, entry);
+                    let spec = attr_ref(&unit, entry, gimli::DW_AT_specification)
+                        .or_else(|| attr_ref(&unit, entry, gimli::DW_AT_abstract_origin));
+                    match (addr, name) {
+                        (Some(addr), Some(name)) => index.variables.push(GlobalVariable {
+                            name,
+                            addr,
+                            type_ref,
+                            external,
+                        }),
+                        (Some(addr), None) => {
+                            if let Some(spec) = spec {
+                                pending_specs.push((addr, spec, external));
+                            }
+                        }
+                        (None, name) => {
+                            declarations.insert(offset, (name, type_ref, external));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (addr, spec, external) in pending_specs {
+        if let Some((Some(name), type_ref, declared_external)) = declarations.get(&spec) {
+            index.variables.push(GlobalVariable {
+                name: name.clone(),
+                addr,
+                type_ref: *type_ref,
+                external: external || *declared_external,
+            });
+        }
+    }
+    index.variables.sort_by(|a, b| b.external.cmp(&a.external).then_with(|| a.addr.cmp(&b.addr)));
+    Ok(index)
+}
+
+/// Unique `DW_AT_producer` strings (compiler version and flags) of all compilation units.
+pub fn read_producers(elf_path: &str, sections: &[SectionInfo]) -> Result<Vec<String>, String> {
+    let (loaded, endian, _) = load_debug_sections(elf_path, sections)?;
+    let dwarf = loaded.borrow(|data| EndianSlice::new(data.as_slice(), endian));
+    let mut producers = Vec::new();
+    let mut units = dwarf.units();
+    while let Some(header) = units.next().map_err(dwarf_error)? {
+        let unit = dwarf.unit(header).map_err(dwarf_error)?;
+        let mut entries = unit.entries();
+        if let Some((_, entry)) = entries.next_dfs().map_err(dwarf_error)? {
+            if let Some(producer) = attr_string(&dwarf, &unit, entry, gimli::DW_AT_producer) {
+                if !producers.contains(&producer) {
+                    producers.push(producer);
+                }
+            }
+        }
+    }
+    producers.sort();
+    Ok(producers)
+}
+
+fn load_debug_sections(
+    elf_path: &str,
+    sections: &[SectionInfo],
+) -> Result<(gimli::DwarfSections<Vec<u8>>, RunTimeEndian, u8), String> {
+    let mut ident = [0u8; 6];
+    fs::File::open(elf_path)
+        .and_then(|mut file| file.read_exact(&mut ident))
+        .map_err(|e| format!("Failed to read ELF file {}: {}", elf_path, e))?;
+    if &ident[..4] != b"\x7fELF" {
+        return Err(format!("{} is not an ELF file.", elf_path));
+    }
+    let endian = if ident[5] == 2 { RunTimeEndian::Big } else { RunTimeEndian::Little };
+    let address_size = if ident[4] == 2 { 8 } else { 4 };
+
+    if !sections.iter().any(|s| s.name == ".debug_info") {
+        return Err("ELF has no .debug_info section. Rebuild with -g.".to_string());
+    }
+    let loaded = gimli::DwarfSections::load(|id| -> Result<Vec<u8>, String> {
+        match sections.iter().find(|s| s.name == id.name()) {
+            Some(section) => read_section_bytes(elf_path, section),
+            None => Ok(Vec::new()),
+        }
+    })?;
+    Ok((loaded, endian, address_size))
+}
+
+impl DwarfIndex {
+    pub fn find_variable(&self, name: &str) -> Option<&GlobalVariable> {
+        self.variables.iter().find(|v| v.name == name)
+    }
+
+    /// Resolves a data address to the innermost member or element that contains it.
+    pub fn data_path(&self, addr: u64) -> Option<DataPath> {
+        let variable = self
+            .variables
+            .iter()
+            .filter_map(|v| Some((v, self.type_size(v.type_ref).filter(|size| *size > 0)?)))
+            .filter(|(v, size)| addr >= v.addr && addr - v.addr < *size)
+            .min_by_key(|(_, size)| *size)?
+            .0;
+        let mut path = DataPath {
+            path: variable.name.clone(),
+            type_name: self.type_name(variable.type_ref),
+            offset: addr - variable.addr,
+            padding: false,
+        };
+        let mut type_ref = variable.type_ref;
+        for _ in 0..MAX_TYPE_DEPTH {
+            let (_, entry) = match self.resolve(type_ref) {
+                Some(resolved) => resolved,
+                None => break,
+            };
+            match &entry.kind {
+                TypeKind::Struct { members, .. } => {
+                    let offset = path.offset;
+                    let member = members.iter().find(|m| {
+                        let (start, size) = self.member_span(m);
+                        offset >= start && offset < start + size
+                    });
+                    let member = match member {
+                        Some(member) => member,
+                        None => {
+                            path.padding = true;
+                            break;
+                        }
+                    };
+                    if let Some(name) = member.name.as_ref() {
+                        path.path = format!("{}.{}", path.path, name);
+                    }
+                    path.offset -= self.member_span(member).0;
+                    path.type_name = self.type_name(member.type_ref);
+                    type_ref = member.type_ref;
+                    if member.bit_size.is_some() {
+                        break;
+                    }
+                }
+                TypeKind::Array { element, dims } => {
+                    let element_size = match self.type_size(*element).filter(|size| *size > 0) {
+                        Some(size) => size,
+                        None => break,
+                    };
+                    let mut stride = dims.iter().skip(1).map(|dim| dim.unwrap_or(1)).product::<u64>() * element_size;
+                    for (idx, _) in dims.iter().enumerate() {
+                        path.path = format!("{}[{}]", path.path, path.offset / stride);
+                        path.offset %= stride;
+                        stride /= dims.get(idx + 1).copied().flatten().unwrap_or(1).max(1);
+                    }
+                    path.type_name = self.type_name(*element);
+                    type_ref = *element;
+                }
+                _ => break,
+            }
+        }
+        Some(path)
+    }
+
+    /// Byte offset and size of a member; bitfields cover the bytes holding their bits.
+    fn member_span(&self, member: &Member) -> (u64, u64) {
+        match member.bit_size {
+            Some(bits) => {
+                let bit_offset = member.bit_offset.unwrap_or(member.offset * 8);
+                (bit_offset / 8, (bit_offset % 8 + bits).div_ceil(8))
+            }
+            None => (member.offset, self.type_size(member.type_ref).unwrap_or(0)),
+        }
+    }
+
+    /// Follows typedefs and cv-qualifiers to the underlying type.
+    pub fn resolve(&self, type_ref: Option<u64>) -> Option<(u64, &TypeEntry)> {
+        let mut current = type_ref?;
+        for _ in 0..MAX_TYPE_DEPTH {
+            let entry = self.types.get(&current)?;
+            match &entry.kind {
+                TypeKind::Typedef { target } | TypeKind::Qualified { target, .. } => current = (*target)?,
+                _ => return Some((current, entry)),
+            }
+        }
+        None
+    }
+
+    pub fn type_size(&self, type_ref: Option<u64>) -> Option<u64> {
+        let (_, entry) = self.resolve(type_ref)?;
+        match &entry.kind {
+            TypeKind::Base { size, .. }
+            | TypeKind::Struct { size, .. }
+            | TypeKind::Enum { size, .. }
+            | TypeKind::Pointer { size, .. } => Some(*size),
+            TypeKind::Array { element, dims } => {
+                let element_size = self.type_size(*element)?;
+                dims.iter().try_fold(element_size, |acc, dim| Some(acc * (*dim)?))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn type_name(&self, type_ref: Option<u64>) -> String {
+        self.type_name_depth(type_ref, 0)
+    }
+
+    fn type_name_depth(&self, type_ref: Option<u64>, depth: usize) -> String {
+        let entry = match type_ref.and_then(|r| self.types.get(&r)) {
+            Some(entry) => entry,
+            None => return "void".to_string(),
+        };
+        if depth > MAX_TYPE_DEPTH {
+            return "...".to_string();
+        }
+        match &entry.kind {
+            TypeKind::Base { .. } | TypeKind::Typedef { .. } | TypeKind::Unknown => {
+                entry.name.clone().unwrap_or_else(|| "?".to_string())
+            }
+            TypeKind::Struct { keyword, .. } => {
+                format!("{} {}", keyword, entry.name.as_deref().unwrap_or("<anonymous>"))
+            }
+            TypeKind::Enum { .. } => format!("enum {}", entry.name.as_deref().unwrap_or("<anonymous>")),
+            TypeKind::Pointer { target, .. } => format!("{} *", self.type_name_depth(*target, depth + 1)),
+            TypeKind::Array { element, dims } => {
+                let dims: String = dims
+                    .iter()
+                    .map(|dim| dim.map(|d| format!("[{}]", d)).unwrap_or_else(|| "[]".to_string()))
+                    .collect();
+                format!("{}{}", self.type_name_depth(*element, depth + 1), dims)
+            }
+            TypeKind::Qualified { keyword, target } => {
+                format!("{} {}", keyword, self.type_name_depth(*target, depth + 1))
+            }
+            TypeKind::Subroutine => "fn".to_string(),
+        }
+    }
+}
+
+fn dwarf_error(error: gimli::Error) -> String {
+    format!("Failed to parse DWARF: {}", error)
+}
+
+fn attr_string(
+    dwarf: &gimli::Dwarf<Reader<'_>>,
+    unit: &gimli::Unit<Reader<'_>>,
+    entry: &gimli::DebuggingInformationEntry<Reader<'_>>,
+    name: gimli::DwAt,
+) -> Option<String> {
+    let value = entry.attr_value(name).ok().flatten()?;
+    let raw = dwarf.attr_string(unit, value).ok()?;
+    Some(raw.to_string_lossy().into_owned())
+}
+
+fn attr_ref(
+    unit: &gimli::Unit<Reader<'_>>,
+    entry: &gimli::DebuggingInformationEntry<Reader<'_>>,
+    name: gimli::DwAt,
+) -> Option<u64> {
+    match entry.attr_value(name).ok().flatten()? {
+        AttributeValue::UnitRef(offset) => offset.to_debug_info_offset(&unit.header).map(|o| o.0 as u64),
+        AttributeValue::DebugInfoRef(offset) => Some(offset.0 as u64),
+        _ => None,
+    }
+}
+
+fn attr_udata(entry: &gimli::DebuggingInformationEntry<Reader<'_>>, name: gimli::DwAt) -> Option<u64> {
+    entry.attr(name).ok().flatten()?.udata_value()
+}
+
+fn member_offset(entry: &gimli::DebuggingInformationEntry<Reader<'_>>) -> Option<u64> {
+    let attr = entry.attr(gimli::DW_AT_data_member_location).ok().flatten()?;
+    if let Some(value) = attr.udata_value() {
+        return Some(value);
+    }
+    // DWARF 2 style: a location expression of the form DW_OP_plus_uconst <offset>.
+    let expr = attr.exprloc_value()?;
+    let bytes = expr.0.slice();
+    if bytes.first() != Some(&0x23) {
+        return None;
+    }
+    let mut value = 0u64;
+    let mut shift = 0;
+    for byte in bytes[1..].iter() {
+        // A ULEB128 longer than 64 bits is malformed.
+        if shift >= 64 {
+            return None;
+        }
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+    None
+}
+
+/// Converts DWARF 2/3 `DW_AT_bit_offset` (counted from the MSB of the storage unit)
+/// into a bit offset from the start of the member's storage.
+fn legacy_bit_offset(entry: &gimli::DebuggingInformationEntry<Reader<'_>>, big_endian: bool) -> Option<u64> {
+    let bit_offset = attr_udata(entry, gimli::DW_AT_bit_offset)?;
+    let bit_size = attr_udata(entry, gimli::DW_AT_bit_size)?;
+    let storage_bits = attr_udata(entry, gimli::DW_AT_byte_size).unwrap_or(4) * 8;
+    let base = member_offset(entry).unwrap_or(0) * 8;
+    if big_endian {
+        Some(base + bit_offset)
+    } else {
+        Some(base + storage_bits.checked_sub(bit_offset + bit_size)?)
+    }
+}
+
+fn variable_address(
+    dwarf: &gimli::Dwarf<Reader<'_>>,
+    unit: &gimli::Unit<Reader<'_>>,
+    entry: &gimli::DebuggingInformationEntry<Reader<'_>>,
+) -> Option<u64> {
+    let expr = entry.attr(gimli::DW_AT_location).ok().flatten()?.exprloc_value()?;
+    let mut ops = expr.operations(unit.encoding());
+    let first = ops.next().ok().flatten()?;
+    // Only plain static addresses; TLS and computed locations are not globals.
+    if ops.next().ok().flatten().is_some() {
+        return None;
+    }
+    match first {
+        gimli::Operation::Address { address } => Some(address),
+        gimli::Operation::AddressIndex { index } => dwarf.address(unit, index).ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,352 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::analyzer::{analyze_firmware, cache_dir, resolve_pc, AnalyzeParams, AppState, PcLookupResult};
+use crate::fs_utils::write_atomic;
+use crate::identity::read_firmware_identity;
+use crate::toolchain::ToolchainConfig;
+
+const LIBRARY_FILE: &str = "elf-library.json";
+const MAX_SCAN_DEPTH: usize = 8;
+const MAX_CRASH_ADDRESSES: usize = 64;
+/// Registers that hold code addresses, after which a bare hex word is taken as one.
+const CODE_REGISTERS: &[&str] = &["pc", "lr", "ra", "epc", "mepc", "sepc"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ElfLibrary {
+    pub directories: Vec<String>,
+    pub entries: Vec<ElfLibraryEntry>,
+}
+
+/// `version` is taken from the first dotted version number in the path relative to the
+/// registered directory, e.g. `releases/v2.4.1/app.elf`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElfLibraryEntry {
+    pub elf_path: String,
+    pub build_id: Option<String>,
+    pub version: Option<String>,
+    pub image_sha256: String,
+    pub size: u64,
+    pub modified: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashMatchParams {
+    pub log: String,
+    pub toolchain: Option<ToolchainConfig>,
+    /// Session to resolve against when no library entry matches the log.
+    pub session_id: Option<String>,
+}
+
+/// `matched_by` is `build_id`, `version` or `current` when no library entry matched and
+/// the addresses were resolved against the given session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashMatch {
+    pub matched_by: String,
+    pub entry: Option<ElfLibraryEntry>,
+    pub session_id: Option<String>,
+    pub addresses: Vec<PcLookupResult>,
+}
+
+#[tauri::command]
+pub fn list_elf_library(app: tauri::AppHandle) -> Result<ElfLibrary, String> {
+    load_library(&app)
+}
+
+/// Adds `directory` to the library (if new) and rescans every registered directory.
+#[tauri::command]
+pub fn register_elf_library(app: tauri::AppHandle, directory: String) -> Result<ElfLibrary, String> {
+    let directory = directory.trim().to_string();
+    if !Path::new(&directory).is_dir() {
+        return Err(format!("ELF library path {} is not a directory.", directory));
+    }
+    let mut library = load_library(&app)?;
+    if !library.directories.contains(&directory) {
+        library.directories.push(directory);
+    }
+    library = rescan(library);
+    store_library(&app, &library)?;
+    Ok(library)
+}
+
+#[tauri::command]
+pub fn remove_elf_library(app: tauri::AppHandle, directory: String) -> Result<ElfLibrary, String> {
+    let mut library = load_library(&app)?;
+    library.directories.retain(|dir| dir != directory.trim());
+    library = rescan(library);
+    store_library(&app, &library)?;
+    Ok(library)
+}
+
+/// Picks the ELF a crash log belongs to (by build-id, then by version string), analyzes it
+/// unless a session already has it open, and resolves every hex address in the log against it.
+#[tauri::command]
+pub fn match_crash_log(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    params: CrashMatchParams,
+) -> Result<CrashMatch, String> {
+    let library = load_library(&app)?;
+    let log = params.log.to_ascii_lowercase();
+    let tokens: HashSet<&str> = log
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '.' && c != '-')
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    let by_build_id = library.entries.iter().find(|entry| {
+        entry
+            .build_id
+            .as_deref()
+            .map(|id| tokens.contains(id) || (id.len() >= 16 && tokens.iter().any(|t| t.len() >= 8 && id.starts_with(t))))
+            .unwrap_or(false)
+    });
+    let by_version = || {
+        library
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry
+                    .version
+                    .as_deref()
+                    .map(|version| tokens.contains(version) || tokens.contains(format!("v{}", version).as_str()))
+                    .unwrap_or(false)
+            })
+            .max_by_key(|entry| entry.modified)
+    };
+    let (matched_by, entry) = match by_build_id {
+        Some(entry) => ("build_id", Some(entry.clone())),
+        None => match by_version() {
+            Some(entry) => ("version", Some(entry.clone())),
+            None => ("current", None),
+        },
+    };
+
+    let session_id = match entry.as_ref() {
+        Some(entry) => match open_session_for(&state, &entry.elf_path)? {
+            Some(id) => Some(id),
+            None => {
+                let result = analyze_firmware(
+                    app.clone(),
+                    state.clone(),
+                    AnalyzeParams {
+                        elf_path: entry.elf_path.clone(),
+                        map_path: None,
+                        linker_script_path: None,
+                        toolchain: params.toolchain.clone(),
+                        region_thresholds: None,
+                        stack_margin_bytes: None,
+                        svd_path: None,
+                        section_rules: None,
+                    },
+                )?;
+                Some(result.meta.cache.key)
+            }
+        },
+        None => params.session_id.clone(),
+    };
+
+    let mut addresses = Vec::new();
+    if let Some(id) = session_id.as_deref() {
+        let session = state.session(id)?;
+        for address in extract_addresses(&params.log) {
+            if let Ok(result) = resolve_pc(&session, address) {
+                addresses.push(result);
+            }
+        }
+    }
+    Ok(CrashMatch {
+        matched_by: matched_by.to_string(),
+        entry,
+        session_id,
+        addresses,
+    })
+}
+
+fn open_session_for(state: &AppState, elf_path: &str) -> Result<Option<String>, String> {
+    let sessions = state.sessions.lock().map_err(|_| "Failed to read sessions.".to_string())?;
+    Ok(sessions.iter().find_map(|(id, session)| {
+        let analysis = session.analysis.lock().ok()?;
+        (analysis.as_ref()?.meta.elf_path == elf_path).then(|| id.clone())
+    }))
+}
+
+fn rescan(library: ElfLibrary) -> ElfLibrary {
+    let mut entries: Vec<ElfLibraryEntry> = Vec::new();
+    for directory in library.directories.iter() {
+        let mut files = Vec::new();
+        collect_elf_files(Path::new(directory), 0, &mut files);
+        for path in files {
+            let elf_path = path.to_string_lossy().to_string();
+            let metadata = match fs::metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            // Unchanged files keep their previous index entry.
+            let previous = library
+                .entries
+                .iter()
+                .find(|entry| entry.elf_path == elf_path && entry.size == metadata.len() && entry.modified == modified);
+            if let Some(previous) = previous {
+                entries.push(previous.clone());
+                continue;
+            }
+            let identity = match read_firmware_identity(&elf_path) {
+                Ok(identity) => identity,
+                Err(_) => continue,
+            };
+            let relative = path.strip_prefix(directory).unwrap_or(&path).to_string_lossy().to_string();
+            entries.push(ElfLibraryEntry {
+                elf_path,
+                build_id: identity.build_id,
+                version: find_version(&relative),
+                image_sha256: identity.image_sha256,
+                size: metadata.len(),
+                modified,
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.elf_path.cmp(&b.elf_path));
+    entries.dedup_by(|a, b| a.elf_path == b.elf_path);
+    ElfLibrary {
+        directories: library.directories,
+        entries,
+    }
+}
+
+fn collect_elf_files(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    if depth > MAX_SCAN_DEPTH {
+        return;
+    }
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_elf_files(&path, depth + 1, out);
+        } else if is_elf_file(&path) {
+            out.push(path);
+        }
+    }
+}
+
+fn is_elf_file(path: &Path) -> bool {
+    let mut magic = [0u8; 4];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .map(|_| &magic == b"\x7fELF")
+        .unwrap_or(false)
+}
+
+fn find_version(relative_path: &str) -> Option<String> {
+    relative_path
+        .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .map(|part| part.trim_matches('.'))
+        .find(|part| part.split('.').count() >= 2 && part.split('.').all(|n| !n.is_empty()))
+        .map(|part| part.to_string())
+}
+
+/// Hex words in the log that look like addresses: `0x` prefixed words, and bare words of up
+/// to 16 hex digits right after a code register name (`PC = 08001a3c`, `LR: 0800124f`).
+/// Other bare words (magic values, timestamps, commit hashes) are left out.
+fn extract_addresses(log: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut addresses = Vec::new();
+    let mut previous = "";
+    for token in log.split(|c: char| !c.is_ascii_alphanumeric()).filter(|token| !token.is_empty()) {
+        let after_register = CODE_REGISTERS.iter().any(|name| previous.eq_ignore_ascii_case(name));
+        previous = token;
+        let digits = match token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+            Some(digits) => digits,
+            None if after_register => token,
+            None => continue,
+        };
+        if digits.is_empty() || digits.len() > 16 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            continue;
+        }
+        let normalized = format!("0x{}", digits.to_ascii_lowercase());
+        if seen.insert(normalized.clone()) {
+            addresses.push(normalized);
+        }
+        if addresses.len() >= MAX_CRASH_ADDRESSES {
+            break;
+        }
+    }
+    addresses
+}
+
+fn load_library(app: &tauri::AppHandle) -> Result<ElfLibrary, String> {
+    let path = cache_dir(app)?.join(LIBRARY_FILE);
+    if !path.exists() {
+        return Ok(ElfLibrary::default());
+    }
+    let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read ELF library: {}", e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse ELF library {}: {}", path.display(), e))
+}
+
+fn store_library(app: &tauri::AppHandle, library: &ElfLibrary) -> Result<(), String> {
+    let path = cache_dir(app)?.join(LIBRARY_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create cache dir: {}", e))?;
+    }
+    let json = serde_json::to_string(library).map_err(|e| format!("Failed to serialize ELF library: {}", e))?;
+    write_atomic(&path, json.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HARD_FAULT_LOG: &str = "\
+[12345678] HardFault_Handler
+git 1a2b3c4d build 20240611
+R0  = 20000100
+R1  = 00000000
+R2  = deadbeef
+R3  = 12345678
+R12 = 00000000
+LR  = 0800124f
+PC  = 08001a3c
+xPSR= 61000000
+CFSR = 0x00008200
+BFAR = 0x40021000
+";
+
+    #[test]
+    fn takes_code_registers_and_prefixed_words_from_hard_fault_dump() {
+        assert_eq!(
+            extract_addresses(HARD_FAULT_LOG),
+            vec!["0x0800124f", "0x08001a3c", "0x00008200", "0x40021000"]
+        );
+    }
+
+    #[test]
+    fn skips_bare_words_outside_register_context() {
+        assert!(extract_addresses("magic deadbeef at 12345678, commit cafebabe").is_empty());
+        assert_eq!(extract_addresses("Backtrace: 0x400d1234:0x3ffb1f20"), vec!["0x400d1234", "0x3ffb1f20"]);
+        assert_eq!(extract_addresses("pc: 0800ABCD"), vec!["0x0800abcd"]);
+    }
+
+    #[test]
+    fn caps_the_address_count() {
+        let log: String = (0..100).map(|n| format!("0x{:08x} ", 0x0800_0000 + n * 4)).collect();
+        assert_eq!(extract_addresses(&log).len(), MAX_CRASH_ADDRESSES);
+    }
+
+    #[test]
+    fn finds_dotted_versions_in_paths() {
+        assert_eq!(find_version("releases/v2.4.1/app.elf").as_deref(), Some("2.4.1"));
+        assert_eq!(find_version("nightly/app.elf"), None);
+    }
+}
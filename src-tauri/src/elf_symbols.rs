@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::analyzer::{parse_hex_str, SymbolInfo};
+use crate::identity::ElfReader;
+
+const SHT_SYMTAB: u64 = 2;
+const STT_SECTION: u64 = 3;
+const STT_FILE: u64 = 4;
+const SHN_UNDEF: u64 = 0;
+const SHN_ABS: u64 = 0xfff1;
+const SHN_COMMON: u64 = 0xfff2;
+
+/// `.symtab` entries by name as (value, section index), plus the section names by index.
+struct SymbolTable {
+    section_names: Vec<String>,
+    entries: HashMap<String, Vec<(u64, u64)>>,
+}
+
+/// Records the ELF section each symbol is defined in, taken from the `.symtab` entry with
+/// the same name and value. nm's type letter only says text/data/bss, which puts symbols
+/// in `.ccmram`, `.itcm` or `.noinit` in the wrong bucket.
+pub fn apply_symbol_sections(symbols: &mut [SymbolInfo], elf_path: &str) -> Result<(), String> {
+    let bytes = fs::read(elf_path).map_err(|e| format!("Failed to read ELF file {}: {}", elf_path, e))?;
+    let table = read_symbol_table(&bytes).ok_or_else(|| format!("Failed to parse the symbol table of {}.", elf_path))?;
+    for symbol in symbols.iter_mut() {
+        let entries = match table.entries.get(&symbol.name) {
+            Some(entries) => entries,
+            None => continue,
+        };
+        // Thumb function values carry bit 0, which nm clears.
+        let addr = symbol.addr.as_deref().and_then(parse_hex_str).map(|addr| addr & !1);
+        let entry = entries
+            .iter()
+            .find(|(value, _)| Some(value & !1) == addr)
+            .or_else(|| entries.first().filter(|_| entries.len() == 1));
+        if let Some((_, index)) = entry {
+            symbol.section_index = Some(*index as u32);
+            symbol.section = Some(section_label(&table.section_names, *index));
+        }
+    }
+    Ok(())
+}
+
+/// Special indices use objdump's spelling.
+fn section_label(names: &[String], index: u64) -> String {
+    match index {
+        SHN_UNDEF => "*UND*".to_string(),
+        SHN_ABS => "*ABS*".to_string(),
+        SHN_COMMON => "*COM*".to_string(),
+        _ => names
+            .get(index as usize)
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| format!("#{}", index)),
+    }
+}
+
+fn read_symbol_table(bytes: &[u8]) -> Option<SymbolTable> {
+    let elf = ElfReader::new(bytes)?;
+    let (shoff, shentsize, shnum, shstrndx) = if elf.is_64 {
+        (elf.uint(40, 8)?, elf.uint(58, 2)?, elf.uint(60, 2)?, elf.uint(62, 2)?)
+    } else {
+        (elf.uint(32, 4)?, elf.uint(46, 2)?, elf.uint(48, 2)?, elf.uint(50, 2)?)
+    };
+    // (name offset, type, file offset, size, link, entry size)
+    let mut headers = Vec::new();
+    for index in 0..shnum as usize {
+        let base = shoff as usize + index * shentsize as usize;
+        let header = if elf.is_64 {
+            (
+                elf.uint(base, 4)?,
+                elf.uint(base + 4, 4)?,
+                elf.uint(base + 24, 8)?,
+                elf.uint(base + 32, 8)?,
+                elf.uint(base + 40, 4)?,
+                elf.uint(base + 56, 8)?,
+            )
+        } else {
+            (
+                elf.uint(base, 4)?,
+                elf.uint(base + 4, 4)?,
+                elf.uint(base + 16, 4)?,
+                elf.uint(base + 20, 4)?,
+                elf.uint(base + 24, 4)?,
+                elf.uint(base + 36, 4)?,
+            )
+        };
+        headers.push(header);
+    }
+    let string_at = |table: usize, offset: u64| -> Option<String> {
+        let (_, _, start, size, _, _) = *headers.get(table)?;
+        let strings = bytes.get(start as usize..(start + size) as usize)?;
+        let tail = strings.get(offset as usize..)?;
+        let end = tail.iter().position(|b| *b == 0)?;
+        Some(String::from_utf8_lossy(&tail[..end]).into_owned())
+    };
+    let section_names = headers
+        .iter()
+        .map(|(name, ..)| string_at(shstrndx as usize, *name).unwrap_or_default())
+        .collect();
+
+    let mut entries: HashMap<String, Vec<(u64, u64)>> = HashMap::new();
+    for (_, kind, offset, size, link, entsize) in headers.iter().copied() {
+        if kind != SHT_SYMTAB || entsize == 0 {
+            continue;
+        }
+        for index in 1..(size / entsize) as usize {
+            let base = offset as usize + index * entsize as usize;
+            let (name, info, shndx, value) = if elf.is_64 {
+                (elf.uint(base, 4)?, elf.uint(base + 4, 1)?, elf.uint(base + 6, 2)?, elf.uint(base + 8, 8)?)
+            } else {
+                (elf.uint(base, 4)?, elf.uint(base + 12, 1)?, elf.uint(base + 14, 2)?, elf.uint(base + 4, 4)?)
+            };
+            if matches!(info & 0xf, STT_SECTION | STT_FILE) {
+                continue;
+            }
+            if let Some(name) = string_at(link as usize, name).filter(|name| !name.is_empty()) {
+                entries.entry(name).or_default().push((value, shndx));
+            }
+        }
+    }
+    Some(SymbolTable { section_names, entries })
+}
@@ -0,0 +1,586 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::analyzer::{parse_hex_str, parse_pc_address, read_section_bytes, AppState, Finding, MemoryRegion, SectionInfo};
+
+const MISMATCH_REPORT_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageInspectParams {
+    pub image_path: String,
+    /// Load address of a raw `.bin`; defaults to the lowest LMA of the ELF's loadable sections.
+    pub base_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageReport {
+    pub image_path: String,
+    pub format: String,
+    pub total_bytes: u64,
+    pub entry: Option<String>,
+    pub ranges: Vec<ImageRange>,
+    pub regions: Vec<RegionCoverage>,
+    pub verification: ImageVerification,
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageRange {
+    pub start: String,
+    pub end: String,
+    pub size: u64,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionCoverage {
+    pub name: String,
+    pub origin: String,
+    pub length: u64,
+    pub covered: u64,
+}
+
+/// Byte-for-byte comparison against the ELF sections that occupy load memory. `extra_bytes`
+/// counts image bytes outside every such section (gap fill, appended checksums).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImageVerification {
+    pub matched: bool,
+    pub compared_bytes: u64,
+    pub mismatched_bytes: u64,
+    pub missing_bytes: u64,
+    pub extra_bytes: u64,
+    pub sections: Vec<SectionCheck>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionCheck {
+    pub name: String,
+    pub lma: String,
+    pub size: u64,
+    pub status: String,
+    pub mismatched_bytes: u64,
+    pub missing_bytes: u64,
+    pub first_mismatch: Option<String>,
+}
+
+/// Contiguous address ranges of an image file, sorted by address and merged.
+#[derive(Debug)]
+pub struct FirmwareImage {
+    pub format: String,
+    pub chunks: Vec<(u64, Vec<u8>)>,
+    pub entry: Option<u64>,
+}
+
+impl FirmwareImage {
+    pub fn load(path: &str, base_address: Option<u64>) -> Result<Self, String> {
+        let bytes = fs::read(path).map_err(|e| format!("Failed to read image file {}: {}", path, e))?;
+        let lower = path.to_ascii_lowercase();
+        let text_start = bytes.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(0);
+        let first = bytes.get(text_start).copied();
+        let srec_like = first == Some(b'S') && bytes.get(text_start + 1).map(u8::is_ascii_digit).unwrap_or(false);
+        let mut image = if lower.ends_with(".hex") || lower.ends_with(".ihex") || first == Some(b':') {
+            parse_intel_hex(&String::from_utf8_lossy(&bytes))?
+        } else if [".srec", ".s19", ".s28", ".s37", ".mot"].iter().any(|ext| lower.ends_with(ext)) || srec_like {
+            parse_srec(&String::from_utf8_lossy(&bytes))?
+        } else {
+            let base = base_address.ok_or_else(|| "A base address is required for raw binary images.".to_string())?;
+            FirmwareImage {
+                format: "bin".to_string(),
+                chunks: vec![(base, bytes)],
+                entry: None,
+            }
+        };
+        image.merge();
+        Ok(image)
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.chunks.iter().map(|(_, data)| data.len() as u64).sum()
+    }
+
+    /// Overlapping records keep the bytes of the record that came last in the file.
+    pub fn merge(&mut self) {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.retain(|(_, data)| !data.is_empty());
+        let mut spans: Vec<(u64, u64)> = chunks
+            .iter()
+            .map(|(addr, data)| (*addr, addr + data.len() as u64))
+            .collect();
+        spans.sort_unstable();
+        let mut merged: Vec<(u64, Vec<u8>)> = Vec::new();
+        for (start, end) in spans {
+            if let Some((last_addr, last)) = merged.last_mut() {
+                let last_end = *last_addr + last.len() as u64;
+                if start <= last_end {
+                    if end > last_end {
+                        last.resize((end - *last_addr) as usize, 0);
+                    }
+                    continue;
+                }
+            }
+            merged.push((start, vec![0; (end - start) as usize]));
+        }
+        // Records are copied in file order, so a later record wins wherever two overlap.
+        for (addr, data) in chunks {
+            let index = merged.partition_point(|(start, _)| *start <= addr) - 1;
+            let (start, target) = &mut merged[index];
+            let offset = (addr - *start) as usize;
+            target[offset..offset + data.len()].copy_from_slice(&data);
+        }
+        self.chunks = merged;
+    }
+
+    /// Image bytes that fall inside `[start, end)`, as (address, bytes) pieces.
+    pub fn slices(&self, start: u64, end: u64) -> Vec<(u64, &[u8])> {
+        let first = self
+            .chunks
+            .partition_point(|(addr, data)| addr + data.len() as u64 <= start);
+        self.chunks[first..]
+            .iter()
+            .take_while(|(addr, _)| *addr < end)
+            .map(|(addr, data)| {
+                let from = start.max(*addr);
+                let to = end.min(addr + data.len() as u64);
+                (from, &data[(from - addr) as usize..(to - addr) as usize])
+            })
+            .collect()
+    }
+
+    /// Intel HEX with 16-byte data records, extended linear address records at every
+    /// 64 KiB boundary and a start linear address record for the entry point.
+    pub fn to_intel_hex(&self) -> String {
+        let mut out = String::new();
+        let mut upper = None;
+        for (addr, data) in self.chunks.iter() {
+            let mut pos = 0usize;
+            while pos < data.len() {
+                let at = addr + pos as u64;
+                if upper != Some(at >> 16) {
+                    upper = Some(at >> 16);
+                    out.push_str(&hex_record(0x04, 0, &((at >> 16) as u16).to_be_bytes()));
+                }
+                // Records never cross a 64 KiB boundary.
+                let room = 0x1_0000 - (at & 0xffff) as usize;
+                let len = (data.len() - pos).min(16).min(room);
+                out.push_str(&hex_record(0x00, (at & 0xffff) as u16, &data[pos..pos + len]));
+                pos += len;
+            }
+        }
+        if let Some(entry) = self.entry {
+            out.push_str(&hex_record(0x05, 0, &(entry as u32).to_be_bytes()));
+        }
+        out.push_str(&hex_record(0x01, 0, &[]));
+        out
+    }
+}
+
+fn hex_record(kind: u8, offset: u16, data: &[u8]) -> String {
+    let mut record = vec![data.len() as u8];
+    record.extend_from_slice(&offset.to_be_bytes());
+    record.push(kind);
+    record.extend_from_slice(data);
+    let checksum = record.iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte)).wrapping_neg();
+    record.push(checksum);
+    let body: String = record.iter().map(|byte| format!("{:02X}", byte)).collect();
+    format!(":{}\n", body)
+}
+
+#[tauri::command]
+pub fn inspect_image(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    params: ImageInspectParams,
+) -> Result<ImageReport, String> {
+    let session = state.session(&session_id)?;
+    let image_path = params.image_path.trim().to_string();
+    if image_path.is_empty() {
+        return Err("Image path is required.".to_string());
+    }
+    let (elf_path, sections, regions) = {
+        let analysis = session.analysis.lock().map_err(|_| "Failed to read analysis state.".to_string())?;
+        let analysis = analysis
+            .as_ref()
+            .ok_or_else(|| "No analysis loaded. Run analysis first.".to_string())?;
+        (
+            analysis.meta.elf_path.clone(),
+            analysis.sections.clone(),
+            analysis.summary.memory_regions.clone(),
+        )
+    };
+    let loadable = load_sections(&sections);
+    let base_address = match params.base_address.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        Some(value) => Some(parse_pc_address(value).map_err(|e| format!("Invalid image base address: {}", e))?),
+        None => loadable.iter().map(|(lma, _)| *lma).min(),
+    };
+    let image = FirmwareImage::load(&image_path, base_address)?;
+
+    let ranges = image
+        .chunks
+        .iter()
+        .map(|(addr, data)| {
+            let end = addr + data.len() as u64;
+            ImageRange {
+                start: format!("0x{:08x}", addr),
+                end: format!("0x{:08x}", end),
+                size: data.len() as u64,
+                region: region_for(&regions, *addr).map(|region| region.name.clone()),
+            }
+        })
+        .collect();
+    let region_coverage = regions
+        .iter()
+        .filter_map(|region| {
+            let origin = parse_hex_str(&region.origin)?;
+            let covered = image
+                .slices(origin, origin + region.length)
+                .iter()
+                .map(|(_, data)| data.len() as u64)
+                .sum();
+            Some(RegionCoverage {
+                name: region.name.clone(),
+                origin: region.origin.clone(),
+                length: region.length,
+                covered,
+            })
+        })
+        .collect();
+    let verification = verify_image(&image, &elf_path, &loadable)?;
+    let findings = compute_image_findings(&verification);
+
+    Ok(ImageReport {
+        image_path,
+        format: image.format.clone(),
+        total_bytes: image.total_bytes(),
+        entry: image.entry.map(|entry| format!("0x{:08x}", entry)),
+        ranges,
+        regions: region_coverage,
+        verification,
+        findings,
+    })
+}
+
+/// Sections whose contents are part of the programmed image, keyed by load address.
+pub fn load_sections(sections: &[SectionInfo]) -> Vec<(u64, &SectionInfo)> {
+    let mut loadable: Vec<(u64, &SectionInfo)> = sections
+        .iter()
+        .filter(|section| section.size > 0)
+        .filter(|section| {
+            section.flags.iter().any(|flag| flag == "LOAD") && section.flags.iter().any(|flag| flag == "CONTENTS")
+        })
+        .filter_map(|section| {
+            let lma = section.lma.as_deref().or(section.vma.as_deref()).and_then(parse_hex_str)?;
+            Some((lma, section))
+        })
+        .collect();
+    loadable.sort_by_key(|(lma, _)| *lma);
+    loadable
+}
+
+fn verify_image(image: &FirmwareImage, elf_path: &str, loadable: &[(u64, &SectionInfo)]) -> Result<ImageVerification, String> {
+    let mut verification = ImageVerification::default();
+    let mut inside = 0u64;
+    for (lma, section) in loadable {
+        let expected = read_section_bytes(elf_path, section)?;
+        let end = lma + expected.len() as u64;
+        let mut covered = 0u64;
+        let mut mismatched = 0u64;
+        let mut first_mismatch = None;
+        for (addr, data) in image.slices(*lma, end) {
+            let offset = (addr - lma) as usize;
+            covered += data.len() as u64;
+            for (idx, (actual, wanted)) in data.iter().zip(&expected[offset..]).enumerate() {
+                if actual != wanted {
+                    mismatched += 1;
+                    first_mismatch.get_or_insert(addr + idx as u64);
+                }
+            }
+        }
+        let missing = expected.len() as u64 - covered;
+        let status = if covered == 0 {
+            "missing"
+        } else if mismatched > 0 {
+            "mismatch"
+        } else if missing > 0 {
+            "partial"
+        } else {
+            "match"
+        };
+        inside += covered;
+        verification.compared_bytes += covered;
+        verification.mismatched_bytes += mismatched;
+        verification.missing_bytes += missing;
+        verification.sections.push(SectionCheck {
+            name: section.name.clone(),
+            lma: format!("0x{:08x}", lma),
+            size: section.size,
+            status: status.to_string(),
+            mismatched_bytes: mismatched,
+            missing_bytes: missing,
+            first_mismatch: first_mismatch.map(|addr| format!("0x{:08x}", addr)),
+        });
+    }
+    verification.extra_bytes = image.total_bytes().saturating_sub(inside);
+    verification.matched =
+        !loadable.is_empty() && verification.mismatched_bytes == 0 && verification.missing_bytes == 0;
+    Ok(verification)
+}
+
+fn compute_image_findings(verification: &ImageVerification) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let bad: Vec<&SectionCheck> = verification
+        .sections
+        .iter()
+        .filter(|section| section.status != "match")
+        .collect();
+    if !bad.is_empty() {
+        findings.push(Finding {
+            id: "IMAGE_MISMATCH".to_string(),
+            severity: "warn".to_string(),
+            value: verification.mismatched_bytes + verification.missing_bytes,
+            items: bad
+                .iter()
+                .take(MISMATCH_REPORT_LIMIT)
+                .map(|section| match section.first_mismatch.as_deref() {
+                    Some(addr) => format!("{}: {} ({} B differ, first at {})", section.name, section.status, section.mismatched_bytes, addr),
+                    None => format!("{}: {} ({} B not in image)", section.name, section.status, section.missing_bytes),
+                })
+                .collect(),
+        });
+    }
+    if verification.extra_bytes > 0 {
+        findings.push(Finding {
+            id: "IMAGE_EXTRA_BYTES".to_string(),
+            severity: "info".to_string(),
+            value: verification.extra_bytes,
+            items: Vec::new(),
+        });
+    }
+    findings
+}
+
+fn region_for(regions: &[MemoryRegion], addr: u64) -> Option<&MemoryRegion> {
+    regions.iter().find(|region| {
+        parse_hex_str(&region.origin)
+            .map(|origin| addr >= origin && addr < origin + region.length)
+            .unwrap_or(false)
+    })
+}
+
+fn parse_hex_bytes(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(text.get(idx..idx + 2)?, 16).ok())
+        .collect()
+}
+
+fn be_value(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, byte| (acc << 8) | *byte as u64)
+}
+
+pub fn parse_intel_hex(text: &str) -> Result<FirmwareImage, String> {
+    let mut image = FirmwareImage {
+        format: "ihex".to_string(),
+        chunks: Vec::new(),
+        entry: None,
+    };
+    let mut base = 0u64;
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = |reason: &str| format!("Invalid Intel HEX record at line {}: {}", idx + 1, reason);
+        let record = line
+            .strip_prefix(':')
+            .and_then(parse_hex_bytes)
+            .ok_or_else(|| invalid("not a hex record"))?;
+        if record.len() < 5 || record.len() != record[0] as usize + 5 {
+            return Err(invalid("length mismatch"));
+        }
+        if record.iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte)) != 0 {
+            return Err(invalid("checksum mismatch"));
+        }
+        let offset = be_value(&record[1..3]);
+        let data = &record[4..record.len() - 1];
+        match record[3] {
+            0x00 => push_data(&mut image.chunks, base + offset, data),
+            0x01 => break,
+            0x02 => base = be_value(data) << 4,
+            0x04 => base = be_value(data) << 16,
+            0x03 if data.len() == 4 => image.entry = Some((be_value(&data[..2]) << 4) + be_value(&data[2..])),
+            0x05 => image.entry = Some(be_value(data)),
+            _ => return Err(invalid("unsupported record type")),
+        }
+    }
+    Ok(image)
+}
+
+pub fn parse_srec(text: &str) -> Result<FirmwareImage, String> {
+    let mut image = FirmwareImage {
+        format: "srec".to_string(),
+        chunks: Vec::new(),
+        entry: None,
+    };
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = |reason: &str| format!("Invalid S-record at line {}: {}", idx + 1, reason);
+        let kind = line
+            .strip_prefix('S')
+            .and_then(|rest| rest.chars().next())
+            .ok_or_else(|| invalid("not an S-record"))?;
+        let record = line.get(2..).and_then(parse_hex_bytes).ok_or_else(|| invalid("not a hex record"))?;
+        if record.is_empty() || record.len() != record[0] as usize + 1 {
+            return Err(invalid("length mismatch"));
+        }
+        if record.iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte)) != 0xff {
+            return Err(invalid("checksum mismatch"));
+        }
+        let addr_len = match kind {
+            '0' | '1' | '5' | '9' => 2,
+            '2' | '6' | '8' => 3,
+            '3' | '7' => 4,
+            _ => return Err(invalid("unsupported record type")),
+        };
+        if record.len() < addr_len + 2 {
+            return Err(invalid("record too short"));
+        }
+        let addr = be_value(&record[1..1 + addr_len]);
+        let data = &record[1 + addr_len..record.len() - 1];
+        match kind {
+            '1' | '2' | '3' => push_data(&mut image.chunks, addr, data),
+            '7' | '8' | '9' => image.entry = Some(addr),
+            _ => {}
+        }
+    }
+    Ok(image)
+}
+
+fn push_data(chunks: &mut Vec<(u64, Vec<u8>)>, addr: u64, data: &[u8]) {
+    if let Some((last_addr, last)) = chunks.last_mut() {
+        if *last_addr + last.len() as u64 == addr {
+            last.extend_from_slice(data);
+            return;
+        }
+    }
+    chunks.push((addr, data.to_vec()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn srec_line(kind: char, addr: &[u8], data: &[u8]) -> String {
+        let mut record = vec![(addr.len() + data.len() + 1) as u8];
+        record.extend_from_slice(addr);
+        record.extend_from_slice(data);
+        let checksum = !record.iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte));
+        record.push(checksum);
+        let body: String = record.iter().map(|byte| format!("{:02X}", byte)).collect();
+        format!("S{}{}\n", kind, body)
+    }
+
+    #[test]
+    fn intel_hex_applies_extended_linear_address() {
+        let text = [
+            hex_record(0x04, 0, &[0x08, 0x00]),
+            hex_record(0x00, 0x0000, &[1, 2, 3, 4]),
+            hex_record(0x00, 0x0004, &[5, 6]),
+            hex_record(0x05, 0, &[0x08, 0x00, 0x01, 0x01]),
+            hex_record(0x01, 0, &[]),
+        ]
+        .concat();
+        let image = parse_intel_hex(&text).unwrap();
+        assert_eq!(image.chunks, vec![(0x0800_0000, vec![1, 2, 3, 4, 5, 6])]);
+        assert_eq!(image.entry, Some(0x0800_0101));
+    }
+
+    #[test]
+    fn intel_hex_applies_extended_segment_address() {
+        let text = [hex_record(0x02, 0, &[0x10, 0x00]), hex_record(0x00, 0x0010, &[0xaa])].concat();
+        let image = parse_intel_hex(&text).unwrap();
+        assert_eq!(image.chunks, vec![(0x1_0010, vec![0xaa])]);
+    }
+
+    #[test]
+    fn intel_hex_rejects_bad_records() {
+        let err = parse_intel_hex(":0100000001FF\n").unwrap_err();
+        assert!(err.contains("checksum mismatch"), "{}", err);
+        let err = parse_intel_hex(":0200000001FD\n").unwrap_err();
+        assert!(err.contains("length mismatch"), "{}", err);
+        assert!(parse_intel_hex("0100000001FE\n").is_err());
+    }
+
+    #[test]
+    fn intel_hex_round_trips_across_64k_boundary() {
+        let data: Vec<u8> = (0..40).collect();
+        let image = FirmwareImage {
+            format: "ihex".to_string(),
+            chunks: vec![(0x0800_fff0, data.clone())],
+            entry: Some(0x0800_0000),
+        };
+        let mut parsed = parse_intel_hex(&image.to_intel_hex()).unwrap();
+        parsed.merge();
+        assert_eq!(parsed.chunks, vec![(0x0800_fff0, data)]);
+        assert_eq!(parsed.entry, Some(0x0800_0000));
+    }
+
+    #[test]
+    fn srec_reads_data_and_entry() {
+        let text = [
+            srec_line('0', &[0, 0], b"hdr"),
+            srec_line('3', &[0x08, 0, 0, 0], &[1, 2]),
+            srec_line('3', &[0x08, 0, 0, 2], &[3]),
+            srec_line('1', &[0x10, 0x00], &[9]),
+            srec_line('7', &[0x08, 0, 0, 0x41], &[]),
+        ]
+        .concat();
+        let mut image = parse_srec(&text).unwrap();
+        image.merge();
+        assert_eq!(image.chunks, vec![(0x1000, vec![9]), (0x0800_0000, vec![1, 2, 3])]);
+        assert_eq!(image.entry, Some(0x0800_0041));
+    }
+
+    #[test]
+    fn srec_rejects_bad_checksum() {
+        let mut line = srec_line('1', &[0x10, 0x00], &[9]);
+        line.replace_range(line.len() - 3..line.len() - 1, "00");
+        let err = parse_srec(&line).unwrap_err();
+        assert!(err.contains("checksum mismatch"), "{}", err);
+    }
+
+    #[test]
+    fn merge_lets_later_records_win() {
+        let mut image = FirmwareImage {
+            format: "ihex".to_string(),
+            chunks: vec![
+                (0x100, vec![1, 1, 1, 1]),
+                (0x102, vec![2, 2, 2, 2]),
+                (0x0fe, vec![3, 3, 3]),
+                (0x200, vec![4]),
+                (0x201, vec![5]),
+            ],
+            entry: None,
+        };
+        image.merge();
+        assert_eq!(
+            image.chunks,
+            vec![(0x0fe, vec![3, 3, 3, 1, 2, 2, 2, 2]), (0x200, vec![4, 5])]
+        );
+    }
+
+    #[test]
+    fn slices_clip_to_the_requested_range() {
+        let image = FirmwareImage {
+            format: "bin".to_string(),
+            chunks: vec![(0x100, vec![1, 2, 3, 4]), (0x200, vec![5, 6])],
+            entry: None,
+        };
+        assert_eq!(image.slices(0x102, 0x201), vec![(0x102, &[3u8, 4][..]), (0x200, &[5u8][..])]);
+    }
+}
@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::analyzer::{cache_dir, hash_string, Finding, MemoryRegion};
+use crate::fs_utils::write_atomic;
+
+const HISTORY_LIMIT: usize = 50;
+const TREND_WINDOW: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionThresholds {
+    pub warn_percent: f64,
+    pub critical_percent: f64,
+}
+
+impl Default for RegionThresholds {
+    fn default() -> Self {
+        Self {
+            warn_percent: 85.0,
+            critical_percent: 95.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageSnapshot {
+    pub timestamp: u64,
+    /// Build-id, or the image digest when the ELF has none.
+    #[serde(alias = "cache_key")]
+    pub build: String,
+    pub regions: Vec<RegionUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionUsage {
+    pub name: String,
+    pub used: u64,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionForecast {
+    pub name: String,
+    pub used: u64,
+    pub length: u64,
+    pub usage_percent: f64,
+    pub samples: usize,
+    pub growth_per_build: f64,
+    pub builds_left: Option<u64>,
+    pub days_left: Option<f64>,
+}
+
+/// Appends the current region usage to the per-ELF history (one entry per distinct build,
+/// so re-analysing an older build, or the same build with other settings, does not add it
+/// again) and returns the history.
+pub fn record_usage_snapshot(
+    app: &tauri::AppHandle,
+    elf_path: &str,
+    build: &str,
+    regions: &[MemoryRegion],
+) -> Result<Vec<UsageSnapshot>, String> {
+    let path = cache_dir(app)?.join(format!("history-{}.json", hash_string(elf_path.trim())));
+    let mut history: Vec<UsageSnapshot> = if path.exists() {
+        let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read usage history: {}", e))?;
+        serde_json::from_str(&contents).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let regions: Vec<RegionUsage> = regions
+        .iter()
+        .filter_map(|region| {
+            region.used.map(|used| RegionUsage {
+                name: region.name.clone(),
+                used,
+                length: region.length,
+            })
+        })
+        .collect();
+    if regions.is_empty() || history.iter().any(|snapshot| snapshot.build == build) {
+        return Ok(history);
+    }
+
+    history.push(UsageSnapshot {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        build: build.to_string(),
+        regions,
+    });
+    if history.len() > HISTORY_LIMIT {
+        history.drain(..history.len() - HISTORY_LIMIT);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create cache dir: {}", e))?;
+    }
+    let json = serde_json::to_string(&history).map_err(|e| format!("Failed to serialize usage history: {}", e))?;
+    write_atomic(&path, json.as_bytes())?;
+    Ok(history)
+}
+
+/// Extrapolates the average growth over the recent builds in `history` for each region.
+pub fn compute_region_forecasts(regions: &[MemoryRegion], history: &[UsageSnapshot]) -> Vec<RegionForecast> {
+    let window = &history[history.len().saturating_sub(TREND_WINDOW)..];
+    regions
+        .iter()
+        .filter(|region| region.length > 0)
+        .filter_map(|region| {
+            let used = region.used?;
+            let samples: Vec<(u64, u64)> = window
+                .iter()
+                .filter_map(|snapshot| {
+                    snapshot
+                        .regions
+                        .iter()
+                        .find(|r| r.name == region.name && r.length == region.length)
+                        .map(|r| (snapshot.timestamp, r.used))
+                })
+                .collect();
+            let mut forecast = RegionForecast {
+                name: region.name.clone(),
+                used,
+                length: region.length,
+                usage_percent: used as f64 * 100.0 / region.length as f64,
+                samples: samples.len(),
+                growth_per_build: 0.0,
+                builds_left: None,
+                days_left: None,
+            };
+            if let (Some(first), Some(last)) = (samples.first(), samples.last()) {
+                if samples.len() >= 2 {
+                    let delta = last.1 as f64 - first.1 as f64;
+                    forecast.growth_per_build = delta / (samples.len() - 1) as f64;
+                    let remaining = region.length.saturating_sub(used) as f64;
+                    if forecast.growth_per_build > 0.0 {
+                        forecast.builds_left = Some((remaining / forecast.growth_per_build).floor() as u64);
+                        let elapsed_days = last.0.saturating_sub(first.0) as f64 / 86_400.0;
+                        if elapsed_days > 0.0 {
+                            forecast.days_left = Some(remaining / (delta / elapsed_days));
+                        }
+                    }
+                }
+            }
+            Some(forecast)
+        })
+        .collect()
+}
+
+pub fn compute_region_findings(forecasts: &[RegionForecast], thresholds: &RegionThresholds) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let describe = |f: &RegionForecast| format!("{} {:.1}% ({} / {} B)", f.name, f.usage_percent, f.used, f.length);
+
+    let overflow: Vec<&RegionForecast> = forecasts.iter().filter(|f| f.used > f.length).collect();
+    if !overflow.is_empty() {
+        findings.push(Finding {
+            id: "REGION_OVERFLOW".to_string(),
+            severity: "warn".to_string(),
+            value: overflow.len() as u64,
+            items: overflow.iter().map(|f| describe(f)).collect(),
+        });
+    }
+
+    let critical: Vec<&RegionForecast> = forecasts
+        .iter()
+        .filter(|f| f.used <= f.length && f.usage_percent >= thresholds.critical_percent)
+        .collect();
+    if !critical.is_empty() {
+        findings.push(Finding {
+            id: "REGION_CRITICAL".to_string(),
+            severity: "warn".to_string(),
+            value: critical.len() as u64,
+            items: critical.iter().map(|f| describe(f)).collect(),
+        });
+    }
+
+    let high: Vec<&RegionForecast> = forecasts
+        .iter()
+        .filter(|f| f.usage_percent >= thresholds.warn_percent && f.usage_percent < thresholds.critical_percent)
+        .collect();
+    if !high.is_empty() {
+        findings.push(Finding {
+            id: "REGION_HIGH_USAGE".to_string(),
+            severity: "info".to_string(),
+            value: high.len() as u64,
+            items: high.iter().map(|f| describe(f)).collect(),
+        });
+    }
+
+    let mut growing: Vec<&RegionForecast> = forecasts
+        .iter()
+        .filter(|f| f.used <= f.length && f.builds_left.is_some())
+        .collect();
+    growing.sort_by_key(|f| f.builds_left);
+    if let Some(soonest) = growing.first().and_then(|f| f.builds_left) {
+        findings.push(Finding {
+            id: "REGION_GROWTH".to_string(),
+            severity: if soonest <= 10 { "warn" } else { "info" }.to_string(),
+            value: soonest,
+            items: growing
+                .iter()
+                .map(|f| {
+                    format!(
+                        "{} +{:.0} B/build, ~{} builds left",
+                        f.name,
+                        f.growth_per_build,
+                        f.builds_left.unwrap_or(0)
+                    )
+                })
+                .collect(),
+        });
+    }
+
+    findings
+}
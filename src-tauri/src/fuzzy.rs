@@ -0,0 +1,170 @@
+/// Subsequence matcher in the spirit of fzf: pattern characters must appear in order,
+/// case-insensitively, and the best alignment is scored. Matches at word starts
+/// (after `_`, `.`, `:` and similar, camelCase humps, digits after letters) and runs of
+/// consecutive characters score higher; gaps cost a little. Longer patterns may leave a
+/// few characters unmatched at a steep cost, so `uarttx` still finds
+/// `HAL_UART_Transmit_DMA`, ranked below names that contain every character.
+pub struct FuzzyPattern {
+    chars: Vec<u8>,
+    max_misses: usize,
+}
+
+const SCORE_MATCH: i32 = 16;
+const GAP_START: i32 = -3;
+const GAP_EXTENSION: i32 = -1;
+const BONUS_BOUNDARY: i32 = 8;
+const BONUS_CAMEL: i32 = 7;
+const BONUS_CONSECUTIVE: i32 = 4;
+const PENALTY_MISS: i32 = 24;
+const NONE: i32 = i32::MIN / 2;
+
+impl FuzzyPattern {
+    pub fn new(pattern: &str) -> Self {
+        let chars: Vec<u8> = pattern
+            .bytes()
+            .filter(|c| !c.is_ascii_whitespace())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        // One unmatched character allowed per five beyond the first; short patterns are strict.
+        let max_misses = chars.len().saturating_sub(1) / 5;
+        Self { chars, max_misses }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Score of the best alignment of the pattern in `name`, or `None` if it does not match.
+    pub fn score(&self, name: &str) -> Option<i32> {
+        let text = name.as_bytes();
+        if self.chars.is_empty() || !self.may_match(text) {
+            return None;
+        }
+        let bonus: Vec<i32> = (0..text.len()).map(|j| position_bonus(text, j)).collect();
+        let width = text.len() + 1;
+        // rows[d][p]: best score with `d` pattern characters skipped so far and the last
+        // match at text position p - 1 (p == 0: nothing matched yet). chunks[d][p] is the
+        // bonus of the first character in the run of consecutive matches ending there.
+        let mut rows = vec![vec![NONE; width]; self.max_misses + 1];
+        let mut chunks = vec![vec![0; width]; self.max_misses + 1];
+        rows[0][0] = 0;
+        for (i, wanted) in self.chars.iter().enumerate() {
+            let mut next = vec![vec![NONE; width]; self.max_misses + 1];
+            let mut next_chunks = vec![vec![0; width]; self.max_misses + 1];
+            for d in 0..=self.max_misses {
+                if d > 0 {
+                    for p in 0..width {
+                        if rows[d - 1][p] > NONE && rows[d - 1][p] - PENALTY_MISS > next[d][p] {
+                            next[d][p] = rows[d - 1][p] - PENALTY_MISS;
+                            next_chunks[d][p] = chunks[d - 1][p];
+                        }
+                    }
+                }
+                let row = &rows[d];
+                // Best predecessor ending two or more characters back, with its gap cost.
+                let mut gapped = NONE;
+                for j in 0..text.len() {
+                    if j > 0 && row[j - 1] > NONE {
+                        gapped = gapped.max(row[j - 1] + GAP_START);
+                    }
+                    if text[j].to_ascii_lowercase() == *wanted {
+                        // Leading characters before the first match are free.
+                        let start = row[0].max(gapped);
+                        let weight = if i == 0 { 2 } else { 1 };
+                        let mut best = (start, start + SCORE_MATCH + bonus[j] * weight, bonus[j]);
+                        if j > 0 && row[j] > NONE {
+                            // A run keeps the bonus of its first character.
+                            let chunk = chunks[d][j].max(bonus[j]);
+                            let score = row[j] + SCORE_MATCH + chunk.max(BONUS_CONSECUTIVE);
+                            if score > best.1 {
+                                best = (row[j], score, chunk);
+                            }
+                        }
+                        if best.0 > NONE && best.1 > next[d][j + 1] {
+                            next[d][j + 1] = best.1;
+                            next_chunks[d][j + 1] = best.2;
+                        }
+                    }
+                    if gapped > NONE {
+                        gapped += GAP_EXTENSION;
+                    }
+                }
+            }
+            rows = next;
+            chunks = next_chunks;
+        }
+        rows.iter().flat_map(|row| row[1..].iter().copied()).filter(|score| *score > NONE).max()
+    }
+
+    /// Cheap rejection: enough pattern characters must occur somewhere in the name.
+    fn may_match(&self, text: &[u8]) -> bool {
+        let mut counts = [0u32; 256];
+        for c in text {
+            counts[c.to_ascii_lowercase() as usize] += 1;
+        }
+        let mut misses = 0;
+        for c in self.chars.iter() {
+            let slot = &mut counts[*c as usize];
+            if *slot > 0 {
+                *slot -= 1;
+            } else {
+                misses += 1;
+                if misses > self.max_misses {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn position_bonus(text: &[u8], j: usize) -> i32 {
+    let current = text[j];
+    let previous = match j.checked_sub(1) {
+        Some(prev) => text[prev],
+        None => return BONUS_BOUNDARY,
+    };
+    if !previous.is_ascii_alphanumeric() && current.is_ascii_alphanumeric() {
+        BONUS_BOUNDARY
+    } else if (previous.is_ascii_lowercase() && current.is_ascii_uppercase())
+        || (previous.is_ascii_alphabetic() && current.is_ascii_digit())
+    {
+        BONUS_CAMEL
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(pattern: &str, name: &str) -> Option<i32> {
+        FuzzyPattern::new(pattern).score(name)
+    }
+
+    #[test]
+    fn finds_uart_transmit_from_uarttx() {
+        assert!(score("uarttx", "HAL_UART_Transmit_DMA").is_some());
+        // Names holding every pattern character rank above the one that needs a miss.
+        assert!(score("uarttx", "uart_tx_start") > score("uarttx", "HAL_UART_Transmit_DMA"));
+        assert!(score("uarttx", "spi_init").is_none());
+    }
+
+    #[test]
+    fn short_patterns_need_every_character_in_order() {
+        assert!(score("uax", "uart_init").is_none());
+        assert!(score("tu", "ut").is_none());
+        assert!(score("UIN", "uart_init").is_some());
+        assert!(FuzzyPattern::new("  ").is_empty());
+        assert!(score("", "uart_init").is_none());
+    }
+
+    #[test]
+    fn prefers_word_starts_and_runs() {
+        assert!(score("ui", "uart_init") > score("ui", "build"));
+        assert!(score("ut", "UartTx") > score("ut", "output"));
+        assert!(score("init", "uart_init") > score("init", "i_n_i_t"));
+        assert!(score("uart", "uart_init") > score("uart", "u_a_r_t"));
+    }
+}
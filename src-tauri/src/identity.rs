@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+
+const PT_LOAD: u32 = 1;
+const PT_NOTE: u32 = 4;
+const NT_GNU_BUILD_ID: u32 = 3;
+
+/// What identifies a firmware build independently of its file name: the GNU build-id
+/// (what devices report in crash logs), ELF header facts and digests of the loaded bytes.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FirmwareIdentity {
+    pub build_id: Option<String>,
+    pub class: String,
+    pub endianness: String,
+    pub machine: String,
+    pub entry: String,
+    pub image_sha256: String,
+    pub segments: Vec<SegmentDigest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentDigest {
+    pub index: usize,
+    pub vaddr: String,
+    pub paddr: String,
+    pub filesz: u64,
+    pub memsz: u64,
+    pub crc32: String,
+    pub sha256: String,
+}
+
+pub struct ElfReader<'a> {
+    bytes: &'a [u8],
+    pub is_64: bool,
+    big_endian: bool,
+}
+
+impl<'a> ElfReader<'a> {
+    /// Checks the ELF magic and picks up class and byte order from the identification bytes.
+    pub fn new(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.get(..4)? != b"\x7fELF" {
+            return None;
+        }
+        Some(Self {
+            bytes,
+            is_64: *bytes.get(4)? == 2,
+            big_endian: *bytes.get(5)? == 2,
+        })
+    }
+
+    pub fn uint(&self, offset: usize, width: usize) -> Option<u64> {
+        let slice = self.bytes.get(offset..offset + width)?;
+        let fold = |acc: u64, byte: &u8| (acc << 8) | *byte as u64;
+        Some(if self.big_endian {
+            slice.iter().fold(0, fold)
+        } else {
+            slice.iter().rev().fold(0, fold)
+        })
+    }
+
+    pub fn word(&self, offset: usize) -> Option<u64> {
+        self.uint(offset, if self.is_64 { 8 } else { 4 })
+    }
+}
+
+pub fn read_firmware_identity(elf_path: &str) -> Result<FirmwareIdentity, String> {
+    let bytes = fs::read(elf_path).map_err(|e| format!("Failed to read ELF file {}: {}", elf_path, e))?;
+    parse_firmware_identity(&bytes).ok_or_else(|| format!("Failed to parse ELF header of {}.", elf_path))
+}
+
+fn parse_firmware_identity(bytes: &[u8]) -> Option<FirmwareIdentity> {
+    let elf = ElfReader::new(bytes)?;
+    let machine = elf.uint(18, 2)?;
+    let entry = elf.word(24)?;
+    let (phoff, phentsize, phnum) = if elf.is_64 {
+        (elf.uint(32, 8)?, elf.uint(54, 2)?, elf.uint(56, 2)?)
+    } else {
+        (elf.uint(28, 4)?, elf.uint(42, 2)?, elf.uint(44, 2)?)
+    };
+
+    let mut identity = FirmwareIdentity {
+        build_id: None,
+        class: if elf.is_64 { "ELF64" } else { "ELF32" }.to_string(),
+        endianness: if elf.big_endian { "big" } else { "little" }.to_string(),
+        machine: machine_name(machine),
+        entry: format!("0x{:08x}", entry),
+        image_sha256: String::new(),
+        segments: Vec::new(),
+    };
+    let mut image = Sha256::new();
+    for index in 0..phnum as usize {
+        let base = phoff as usize + index * phentsize as usize;
+        let kind = elf.uint(base, 4)? as u32;
+        // The 64-bit layout moves p_flags up front, shifting every other field.
+        let (offset, vaddr, paddr, filesz, memsz) = if elf.is_64 {
+            (
+                elf.uint(base + 8, 8)?,
+                elf.uint(base + 16, 8)?,
+                elf.uint(base + 24, 8)?,
+                elf.uint(base + 32, 8)?,
+                elf.uint(base + 40, 8)?,
+            )
+        } else {
+            (
+                elf.uint(base + 4, 4)?,
+                elf.uint(base + 8, 4)?,
+                elf.uint(base + 12, 4)?,
+                elf.uint(base + 16, 4)?,
+                elf.uint(base + 20, 4)?,
+            )
+        };
+        let data = match bytes.get(offset as usize..(offset + filesz) as usize) {
+            Some(data) => data,
+            None => continue,
+        };
+        match kind {
+            PT_LOAD if filesz > 0 => {
+                image.update(paddr.to_le_bytes());
+                image.update(data);
+                identity.segments.push(SegmentDigest {
+                    index,
+                    vaddr: format!("0x{:08x}", vaddr),
+                    paddr: format!("0x{:08x}", paddr),
+                    filesz,
+                    memsz,
+                    crc32: format!("0x{:08x}", crc32fast::hash(data)),
+                    sha256: format!("{:x}", Sha256::digest(data)),
+                });
+            }
+            PT_NOTE if identity.build_id.is_none() => {
+                identity.build_id = find_build_id(&elf, data);
+            }
+            _ => {}
+        }
+    }
+    identity.image_sha256 = format!("{:x}", image.finalize());
+    Some(identity)
+}
+
+fn find_build_id(elf: &ElfReader, notes: &[u8]) -> Option<String> {
+    let reader = ElfReader {
+        bytes: notes,
+        is_64: elf.is_64,
+        big_endian: elf.big_endian,
+    };
+    let align4 = |value: usize| (value + 3) & !3;
+    let mut pos = 0;
+    while pos + 12 <= notes.len() {
+        let namesz = reader.uint(pos, 4)? as usize;
+        let descsz = reader.uint(pos + 4, 4)? as usize;
+        let kind = reader.uint(pos + 8, 4)? as u32;
+        let name_start = pos + 12;
+        let desc_start = name_start + align4(namesz);
+        if kind == NT_GNU_BUILD_ID && notes.get(name_start..name_start + namesz) == Some(b"GNU\0") {
+            let desc = notes.get(desc_start..desc_start + descsz)?;
+            return Some(desc.iter().map(|b| format!("{:02x}", b)).collect());
+        }
+        pos = desc_start + align4(descsz);
+    }
+    None
+}
+
+fn machine_name(machine: u64) -> String {
+    let name = match machine {
+        3 => "x86",
+        8 => "MIPS",
+        40 => "ARM",
+        62 => "x86-64",
+        83 => "AVR",
+        94 => "Xtensa",
+        105 => "MSP430",
+        183 => "AArch64",
+        243 => "RISC-V",
+        _ => return format!("0x{:x}", machine),
+    };
+    name.to_string()
+}
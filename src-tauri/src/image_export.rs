@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::analyzer::{parse_hex_str, parse_pc_address, AppState, SectionInfo, SymbolInfo};
+use crate::firmware_image::FirmwareImage;
+use crate::fs_utils::write_atomic;
+
+const DEFAULT_FILL_BYTE: u8 = 0xff;
+/// Largest padded or flattened image the export builds in memory.
+const MAX_IMAGE_BYTES: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageExportParams {
+    pub output_path: String,
+    /// `bin` or `hex`.
+    pub format: String,
+    pub fill_byte: Option<u8>,
+    /// Fill the gaps between segments in HEX output; a flat binary is always contiguous.
+    #[serde(default)]
+    pub fill_gaps: bool,
+    /// Memory region whose end the image is padded up to.
+    pub pad_to_region: Option<String>,
+    pub checksum: Option<ChecksumSpec>,
+}
+
+/// `location` is an address or a symbol name. The digest covers the padded image from its
+/// first to its last byte, gaps counted as fill, skipping the checksum slot itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecksumSpec {
+    /// `crc32` or `sha256`.
+    pub algorithm: String,
+    pub location: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub start: String,
+    pub end: String,
+    pub size: u64,
+    pub written_bytes: u64,
+    pub fill_byte: u8,
+    pub checksum: Option<ChecksumResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecksumResult {
+    pub algorithm: String,
+    pub address: String,
+    pub value: String,
+}
+
+#[tauri::command]
+pub fn export_flash_image(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    params: ImageExportParams,
+) -> Result<ImageExportResult, String> {
+    let session = state.session(&session_id)?;
+    let output_path = params.output_path.trim().to_string();
+    if output_path.is_empty() {
+        return Err("Output path is required.".to_string());
+    }
+    let format = params.format.trim().to_ascii_lowercase();
+    if format != "bin" && format != "hex" {
+        return Err(format!("Unsupported image format {}. Use bin or hex.", params.format));
+    }
+    let fill_byte = params.fill_byte.unwrap_or(DEFAULT_FILL_BYTE);
+    let analysis = session
+        .analysis
+        .lock()
+        .map_err(|_| "Failed to read analysis state.".to_string())?
+        .clone()
+        .ok_or_else(|| "No analysis loaded. Run analysis first.".to_string())?;
+    let elf_path = analysis.meta.elf_path.clone();
+    let elf = fs::read(&elf_path).map_err(|e| format!("Failed to read ELF file {}: {}", elf_path, e))?;
+
+    let mut image = FirmwareImage {
+        format: format.clone(),
+        chunks: Vec::new(),
+        entry: parse_hex_str(&analysis.identity.entry),
+    };
+    for segment in analysis.segments.iter().filter(|s| s.kind == "LOAD" && s.filesz > 0) {
+        let paddr = parse_hex_str(&segment.paddr).ok_or_else(|| format!("Invalid segment address {}.", segment.paddr))?;
+        let bytes = elf
+            .get(segment.offset as usize..(segment.offset + segment.filesz) as usize)
+            .ok_or_else(|| format!("Segment at {} lies outside the ELF file.", segment.paddr))?;
+        image.chunks.push((paddr, bytes.to_vec()));
+    }
+    image.merge();
+    let start = image
+        .chunks
+        .first()
+        .map(|(addr, _)| *addr)
+        .ok_or_else(|| "The ELF has no loadable segments with file contents.".to_string())?;
+    let mut end = image
+        .chunks
+        .last()
+        .map(|(addr, data)| addr + data.len() as u64)
+        .unwrap_or(start);
+    let entry = image.entry;
+    // Padding and the checksum slot are added as records of their own, so a HEX file without
+    // gap fill only holds the segments plus those ranges.
+    let mut chunks = image.chunks;
+
+    if let Some(name) = params.pad_to_region.as_deref().map(str::trim).filter(|name| !name.is_empty()) {
+        let region = analysis
+            .summary
+            .memory_regions
+            .iter()
+            .find(|region| region.name == name)
+            .ok_or_else(|| format!("Memory region {} not found.", name))?;
+        let origin = parse_hex_str(&region.origin).unwrap_or(0);
+        let region_end = origin + region.length;
+        if start < origin || region_end < end {
+            return Err(format!(
+                "Image 0x{:08x}-0x{:08x} does not lie inside region {} (0x{:08x}-0x{:08x}).",
+                start, end, name, origin, region_end
+            ));
+        }
+        check_image_size(start, region_end)?;
+        chunks.push((end, vec![fill_byte; (region_end - end) as usize]));
+        end = region_end;
+    }
+
+    let slot = match params.checksum.as_ref() {
+        Some(spec) => {
+            let size = match spec.algorithm.trim().to_ascii_lowercase().as_str() {
+                "crc32" => 4,
+                "sha256" | "sha-256" => 32,
+                other => return Err(format!("Unsupported checksum algorithm {}.", other)),
+            };
+            let symbols = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+            let addr = resolve_location(&spec.location, &symbols, &analysis.sections, size)?;
+            if addr < start {
+                return Err(format!("Checksum address 0x{:08x} lies before the image start 0x{:08x}.", addr, start));
+            }
+            let regions = &analysis.summary.memory_regions;
+            let in_region = regions.iter().any(|region| {
+                parse_hex_str(&region.origin)
+                    .map(|origin| addr >= origin && addr + size <= origin + region.length)
+                    .unwrap_or(false)
+            });
+            if !regions.is_empty() && !in_region {
+                return Err(format!("Checksum address 0x{:08x} lies outside every memory region.", addr));
+            }
+            chunks.push((addr, vec![fill_byte; size as usize]));
+            end = end.max(addr + size);
+            Some((addr, size))
+        }
+        None => None,
+    };
+
+    let mut output = FirmwareImage {
+        format: format.clone(),
+        chunks,
+        entry,
+    };
+    output.merge();
+    if format == "bin" || params.fill_gaps {
+        check_image_size(start, end)?;
+        let mut flat = vec![fill_byte; (end - start) as usize];
+        for (addr, data) in output.chunks.iter() {
+            let offset = (addr - start) as usize;
+            flat[offset..offset + data.len()].copy_from_slice(data);
+        }
+        output.chunks = vec![(start, flat)];
+    }
+    let checksum = match slot {
+        Some((addr, size)) => {
+            let skip = (addr, addr + size);
+            let (digest, value) = if size == 4 {
+                let mut hasher = crc32fast::Hasher::new();
+                feed_padded(&output.chunks, start, fill_byte, skip, |part| hasher.update(part));
+                let crc = hasher.finalize();
+                let bytes = if analysis.identity.endianness == "big" {
+                    crc.to_be_bytes()
+                } else {
+                    crc.to_le_bytes()
+                };
+                (bytes.to_vec(), format!("0x{:08x}", crc))
+            } else {
+                let mut hasher = Sha256::new();
+                feed_padded(&output.chunks, start, fill_byte, skip, |part| hasher.update(part));
+                let digest = hasher.finalize().to_vec();
+                let value = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+                (digest, value)
+            };
+            // The slot was added as a record, so it lies inside a single merged chunk.
+            let (chunk_addr, data) = output
+                .chunks
+                .iter_mut()
+                .find(|(chunk_addr, data)| addr >= *chunk_addr && addr + size <= *chunk_addr + data.len() as u64)
+                .ok_or_else(|| format!("Checksum slot 0x{:08x} is not part of the image.", addr))?;
+            let offset = (addr - *chunk_addr) as usize;
+            data[offset..offset + size as usize].copy_from_slice(&digest);
+            Some(ChecksumResult {
+                algorithm: if size == 4 { "crc32" } else { "sha256" }.to_string(),
+                address: format!("0x{:08x}", addr),
+                value,
+            })
+        }
+        None => None,
+    };
+
+    let contents = if format == "bin" {
+        output.chunks.pop().map(|(_, data)| data).unwrap_or_default()
+    } else {
+        output.to_intel_hex().into_bytes()
+    };
+    let file_path = PathBuf::from(&output_path);
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    write_atomic(&file_path, &contents)?;
+
+    Ok(ImageExportResult {
+        output_path,
+        format,
+        start: format!("0x{:08x}", start),
+        end: format!("0x{:08x}", end),
+        size: end - start,
+        written_bytes: contents.len() as u64,
+        fill_byte,
+        checksum,
+    })
+}
+
+/// Rejects images whose flat form would not fit in `MAX_IMAGE_BYTES`, such as internal
+/// flash plus external QSPI flash hundreds of megabytes apart.
+fn check_image_size(start: u64, end: u64) -> Result<(), String> {
+    if end - start > MAX_IMAGE_BYTES {
+        return Err(format!(
+            "Image 0x{:08x}-0x{:08x} spans {} bytes, more than the {} byte limit. Export HEX without gap fill instead.",
+            start,
+            end,
+            end - start,
+            MAX_IMAGE_BYTES
+        ));
+    }
+    Ok(())
+}
+
+/// Load address of the checksum slot. Symbols are placed at their run address, so one
+/// inside a section that is copied at startup is translated to its load address.
+fn resolve_location(location: &str, symbols: &[SymbolInfo], sections: &[SectionInfo], size: u64) -> Result<u64, String> {
+    let location = location.trim();
+    if location.is_empty() {
+        return Err("Checksum location is required.".to_string());
+    }
+    let symbol = match symbols.iter().find(|symbol| symbol.name == location && symbol.addr.is_some()) {
+        Some(symbol) => symbol,
+        None => {
+            return parse_pc_address(location)
+                .map_err(|_| format!("Checksum location {} is neither a symbol nor an address.", location))
+        }
+    };
+    if symbol.size > 0 && symbol.size < size {
+        return Err(format!("Checksum symbol {} is {} bytes, {} are needed.", location, symbol.size, size));
+    }
+    let vma = symbol.addr.as_deref().and_then(parse_hex_str).unwrap_or(0);
+    let lma = sections.iter().filter(|section| section.flags.iter().any(|flag| flag == "ALLOC")).find_map(|section| {
+        let start = parse_hex_str(section.vma.as_deref()?)?;
+        let load = parse_hex_str(section.lma.as_deref()?)?;
+        (vma >= start && vma < start + section.size).then(|| load + (vma - start))
+    });
+    Ok(lma.unwrap_or(vma))
+}
+
+/// Feeds the image from `start` to its last byte to `update`, gaps as `fill`, leaving out
+/// the `skip` range.
+fn feed_padded(chunks: &[(u64, Vec<u8>)], start: u64, fill: u8, skip: (u64, u64), mut update: impl FnMut(&[u8])) {
+    let block = [fill; 4096];
+    let mut cursor = start;
+    for (addr, data) in chunks {
+        let mut gap = addr - cursor;
+        while gap > 0 {
+            let len = gap.min(block.len() as u64);
+            update(&block[..len as usize]);
+            gap -= len;
+        }
+        let chunk_end = addr + data.len() as u64;
+        let from = (skip.0.clamp(*addr, chunk_end) - addr) as usize;
+        let to = (skip.1.clamp(*addr, chunk_end) - addr) as usize;
+        update(&data[..from]);
+        update(&data[to..]);
+        cursor = chunk_end;
+    }
+}
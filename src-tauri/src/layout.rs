@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{parse_hex_or_dec, parse_hex_str, Finding, MemoryRegion, SectionInfo};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AddressLayout {
+    pub regions: Vec<LayoutRegion>,
+    pub unmapped: Vec<LayoutEntry>,
+    pub overlaps: Vec<LayoutOverlap>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutRegion {
+    pub name: String,
+    pub start: String,
+    pub end: String,
+    pub length: u64,
+    pub entries: Vec<LayoutEntry>,
+}
+
+/// One span on the address axis. `kind` is `section`, `load_image` (the LMA copy of a
+/// section that runs elsewhere), `alignment` (padding up to the next section's alignment),
+/// `gap` (an unexplained hole) or `free` (unused tail of a region).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutEntry {
+    pub kind: String,
+    pub name: String,
+    pub start: String,
+    pub end: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutOverlap {
+    pub first: String,
+    pub second: String,
+    pub start: String,
+    pub end: String,
+    pub size: u64,
+}
+
+struct Placement {
+    kind: &'static str,
+    name: String,
+    start: u64,
+    end: u64,
+    align: u64,
+}
+
+pub fn build_address_layout(sections: &[SectionInfo], regions: &[MemoryRegion]) -> AddressLayout {
+    let mut placements = collect_placements(sections);
+    placements.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end)));
+
+    let overlaps = find_overlaps(&placements);
+    let ranges: Vec<(u64, u64)> = regions
+        .iter()
+        .map(|region| {
+            let start = parse_hex_or_dec(&region.origin);
+            (start, start.saturating_add(region.length))
+        })
+        .collect();
+
+    let mut per_region: Vec<Vec<&Placement>> = vec![Vec::new(); regions.len()];
+    let mut unmapped = Vec::new();
+    for placement in placements.iter() {
+        match ranges
+            .iter()
+            .position(|(start, end)| placement.start >= *start && placement.start < *end)
+        {
+            Some(idx) => per_region[idx].push(placement),
+            None => unmapped.push(to_entry(placement.kind, &placement.name, placement.start, placement.end)),
+        }
+    }
+
+    let layout_regions = regions
+        .iter()
+        .zip(ranges.iter())
+        .zip(per_region)
+        .map(|((region, (start, end)), items)| LayoutRegion {
+            name: region.name.clone(),
+            start: format_addr(*start),
+            end: format_addr(*end),
+            length: region.length,
+            entries: lay_out_region(*start, *end, &items),
+        })
+        .collect();
+
+    AddressLayout {
+        regions: layout_regions,
+        unmapped,
+        overlaps,
+    }
+}
+
+pub fn compute_layout_findings(layout: &AddressLayout) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !layout.overlaps.is_empty() {
+        findings.push(Finding {
+            id: "SECTION_OVERLAP".to_string(),
+            severity: "warn".to_string(),
+            value: layout.overlaps.len() as u64,
+            items: layout
+                .overlaps
+                .iter()
+                .take(10)
+                .map(|o| format!("{} / {} @ {}", o.first, o.second, o.start))
+                .collect(),
+        });
+    }
+    let gap_total: u64 = layout
+        .regions
+        .iter()
+        .flat_map(|region| region.entries.iter())
+        .filter(|entry| entry.kind == "gap")
+        .map(|entry| entry.size)
+        .sum();
+    if gap_total > 0 {
+        let mut gaps: Vec<&LayoutEntry> = layout
+            .regions
+            .iter()
+            .flat_map(|region| region.entries.iter())
+            .filter(|entry| entry.kind == "gap")
+            .collect();
+        gaps.sort_by_key(|entry| std::cmp::Reverse(entry.size));
+        findings.push(Finding {
+            id: "LAYOUT_GAPS".to_string(),
+            severity: "info".to_string(),
+            value: gap_total,
+            items: gaps.iter().take(5).map(|g| g.name.clone()).collect(),
+        });
+    }
+    findings
+}
+
+fn collect_placements(sections: &[SectionInfo]) -> Vec<Placement> {
+    let mut placements = Vec::new();
+    for section in sections {
+        if section.size == 0 || !section.flags.iter().any(|f| f == "ALLOC") {
+            continue;
+        }
+        // .tbss only describes a TLS template and legitimately shares addresses.
+        if section.flags.iter().any(|f| f == "THREAD_LOCAL") && !section.flags.iter().any(|f| f == "LOAD") {
+            continue;
+        }
+        let vma = section.vma.as_deref().and_then(parse_hex_str);
+        let lma = section.lma.as_deref().and_then(parse_hex_str);
+        if let Some(vma) = vma {
+            placements.push(Placement {
+                kind: "section",
+                name: section.name.clone(),
+                start: vma,
+                end: vma.saturating_add(section.size),
+                align: section.align,
+            });
+        }
+        if let Some(lma) = lma {
+            if Some(lma) != vma && section.flags.iter().any(|f| f == "LOAD") {
+                placements.push(Placement {
+                    kind: "load_image",
+                    name: format!("{} (load)", section.name),
+                    start: lma,
+                    end: lma.saturating_add(section.size),
+                    align: section.align,
+                });
+            }
+        }
+    }
+    placements
+}
+
+/// Every pair of intersecting placements, including sections nested inside a larger one.
+fn find_overlaps(sorted: &[Placement]) -> Vec<LayoutOverlap> {
+    let mut overlaps = Vec::new();
+    for (idx, placement) in sorted.iter().enumerate() {
+        for other in sorted[idx + 1..].iter().take_while(|other| other.start < placement.end) {
+            let end = placement.end.min(other.end);
+            overlaps.push(LayoutOverlap {
+                first: placement.name.clone(),
+                second: other.name.clone(),
+                start: format_addr(other.start),
+                end: format_addr(end),
+                size: end - other.start,
+            });
+        }
+    }
+    overlaps
+}
+
+fn lay_out_region(start: u64, end: u64, items: &[&Placement]) -> Vec<LayoutEntry> {
+    let mut entries = Vec::new();
+    let mut cursor = start;
+    for item in items {
+        if item.start > cursor {
+            // Only the padding that rounds the cursor up to the section's alignment is expected.
+            let aligned = item.align > 1 && cursor.checked_next_multiple_of(item.align) == Some(item.start);
+            let kind = if aligned { "alignment" } else { "gap" };
+            let name = format!("{} before {}", kind, item.name);
+            entries.push(to_entry(kind, &name, cursor, item.start));
+        }
+        entries.push(to_entry(item.kind, &item.name, item.start, item.end));
+        cursor = cursor.max(item.end);
+    }
+    if cursor < end {
+        entries.push(to_entry("free", "free", cursor, end));
+    }
+    entries
+}
+
+fn to_entry(kind: &str, name: &str, start: u64, end: u64) -> LayoutEntry {
+    LayoutEntry {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        start: format_addr(start),
+        end: format_addr(end),
+        size: end.saturating_sub(start),
+    }
+}
+
+fn format_addr(value: u64) -> String {
+    format!("0x{:08x}", value)
+}
@@ -0,0 +1,427 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::analyzer::{parse_hex_or_dec, run_command, Finding};
+
+const OBJECT_BATCH_SIZE: usize = 128;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LinkageReport {
+    pub common_symbols: Vec<CommonSymbol>,
+    pub weak_defaults: Vec<WeakSymbol>,
+    pub weak_overrides: Vec<WeakOverride>,
+    pub duplicate_commons: Vec<DuplicateCommon>,
+    pub objects_scanned: usize,
+    /// Input objects `nm` could not read, so the weak and COMMON checks leave them out.
+    #[serde(default)]
+    pub objects_skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommonSymbol {
+    pub name: String,
+    pub size: u64,
+    pub object: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeakSymbol {
+    pub name: String,
+    pub addr: String,
+    pub size: u64,
+    pub kind: String,
+    pub alias_of: Option<String>,
+    pub is_handler: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeakOverride {
+    pub name: String,
+    pub weak_objects: Vec<String>,
+    pub strong_object: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCommon {
+    pub name: String,
+    pub objects: Vec<String>,
+    pub sizes: Vec<u64>,
+    pub has_definition: bool,
+}
+
+struct ObjectSymbol {
+    object: String,
+    name: String,
+    kind: String,
+    size: u64,
+}
+
+/// Builds the COMMON / weak resolution report from the final ELF symbol table (full
+/// `nm -S` output) and, when the MAP is available, from the input objects it lists.
+pub fn build_linkage_report(
+    nm_path: &str,
+    nm_output: &str,
+    elf_path: &str,
+    map_path: Option<&str>,
+) -> Result<LinkageReport, String> {
+    let mut report = LinkageReport {
+        weak_defaults: parse_weak_defaults(nm_output),
+        ..LinkageReport::default()
+    };
+
+    let map_path = match map_path.map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        Some(path) => path,
+        None => return Ok(report),
+    };
+    let contents =
+        fs::read_to_string(map_path).map_err(|e| format!("Failed to read MAP file {}: {}", map_path, e))?;
+    report.common_symbols = parse_common_symbols(&contents);
+
+    let (inputs, linked) = map_load_inputs(elf_path, map_path, &contents);
+    if inputs.is_empty() {
+        return Ok(report);
+    }
+
+    let mut definitions: HashMap<String, Vec<ObjectSymbol>> = HashMap::new();
+    let mut scanned: HashSet<String> = HashSet::new();
+    let mut outputs = Vec::new();
+    for batch in inputs.chunks(OBJECT_BATCH_SIZE) {
+        let mut args: Vec<&str> = vec!["-A", "-S"];
+        args.extend(batch.iter().map(|path| path.as_str()));
+        if let Ok(output) = run_command(nm_path, &args) {
+            outputs.push(output);
+            continue;
+        }
+        // One unreadable input fails the whole batch; retry one by one to find it.
+        for path in batch {
+            match run_command(nm_path, &["-A", "-S", path]) {
+                Ok(output) => outputs.push(output),
+                Err(_) => report.objects_skipped.push(object_label(path, None)),
+            }
+        }
+    }
+    for output in outputs.iter() {
+        for symbol in parse_object_symbols(output) {
+            if !is_linked_input(&symbol.object, &linked) {
+                continue;
+            }
+            scanned.insert(symbol.object.clone());
+            definitions.entry(symbol.name.clone()).or_default().push(symbol);
+        }
+    }
+    report.objects_scanned = scanned.len();
+
+    let mut names: Vec<&String> = definitions.keys().collect();
+    names.sort();
+    for name in names {
+        let defs = &definitions[name];
+        let weak_objects: Vec<String> = defs
+            .iter()
+            .filter(|def| matches!(def.kind.as_str(), "W" | "V"))
+            .map(|def| def.object.clone())
+            .collect();
+        let strong = defs
+            .iter()
+            .find(|def| matches!(def.kind.as_str(), "T" | "D" | "B" | "R" | "G" | "S"));
+        if let Some(strong) = strong {
+            if !weak_objects.is_empty() {
+                report.weak_overrides.push(WeakOverride {
+                    name: name.clone(),
+                    weak_objects,
+                    strong_object: strong.object.clone(),
+                });
+            }
+        }
+
+        let commons: Vec<&ObjectSymbol> = defs.iter().filter(|def| def.kind == "C").collect();
+        let has_definition = defs.iter().any(|def| matches!(def.kind.as_str(), "D" | "B" | "G" | "S"));
+        if commons.len() > 1 || (!commons.is_empty() && has_definition) {
+            let related: Vec<&ObjectSymbol> = defs
+                .iter()
+                .filter(|def| matches!(def.kind.as_str(), "C" | "D" | "B" | "G" | "S"))
+                .collect();
+            report.duplicate_commons.push(DuplicateCommon {
+                name: name.clone(),
+                objects: related.iter().map(|def| def.object.clone()).collect(),
+                sizes: related.iter().map(|def| def.size).collect(),
+                has_definition,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+pub fn compute_linkage_findings(report: &LinkageReport) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let handlers: Vec<&WeakSymbol> = report.weak_defaults.iter().filter(|s| s.is_handler).collect();
+    if !handlers.is_empty() {
+        findings.push(Finding {
+            id: "WEAK_DEFAULT_HANDLER".to_string(),
+            severity: "warn".to_string(),
+            value: handlers.len() as u64,
+            items: handlers.iter().take(10).map(|s| s.name.clone()).collect(),
+        });
+    }
+
+    if !report.weak_overrides.is_empty() {
+        findings.push(Finding {
+            id: "WEAK_OVERRIDE".to_string(),
+            severity: "info".to_string(),
+            value: report.weak_overrides.len() as u64,
+            items: report
+                .weak_overrides
+                .iter()
+                .take(10)
+                .map(|item| format!("{} <- {}", item.name, item.strong_object))
+                .collect(),
+        });
+    }
+
+    if !report.duplicate_commons.is_empty() {
+        findings.push(Finding {
+            id: "COMMON_DUPLICATE".to_string(),
+            severity: "warn".to_string(),
+            value: report.duplicate_commons.len() as u64,
+            items: report.duplicate_commons.iter().take(10).map(|item| item.name.clone()).collect(),
+        });
+    }
+
+    if !report.objects_skipped.is_empty() {
+        findings.push(Finding {
+            id: "LINKAGE_OBJECTS_SKIPPED".to_string(),
+            severity: "warn".to_string(),
+            value: report.objects_skipped.len() as u64,
+            items: report.objects_skipped.iter().take(10).cloned().collect(),
+        });
+    }
+
+    let common_total: u64 = report.common_symbols.iter().map(|s| s.size).sum();
+    if common_total > 0 {
+        let mut commons: Vec<&CommonSymbol> = report.common_symbols.iter().collect();
+        commons.sort_by_key(|s| std::cmp::Reverse(s.size));
+        findings.push(Finding {
+            id: "COMMON_SYMBOLS".to_string(),
+            severity: "info".to_string(),
+            value: common_total,
+            items: commons.iter().take(5).map(|s| s.name.clone()).collect(),
+        });
+    }
+
+    findings
+}
+
+fn parse_weak_defaults(output: &str) -> Vec<WeakSymbol> {
+    let mut strong_by_addr: HashMap<String, String> = HashMap::new();
+    let mut weak = Vec::new();
+    for line in output.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let (addr, size, kind, name) = match parts.len() {
+            3 => (parts[0], 0, parts[1], parts[2]),
+            n if n >= 4 => (parts[0], u64::from_str_radix(parts[1], 16).unwrap_or(0), parts[2], parts[3]),
+            _ => continue,
+        };
+        match kind {
+            "W" | "w" | "V" | "v" => weak.push((addr.to_string(), size, kind.to_string(), name.to_string())),
+            "T" | "t" => {
+                strong_by_addr.entry(addr.to_string()).or_insert_with(|| name.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    let mut result: Vec<WeakSymbol> = weak
+        .into_iter()
+        .map(|(addr, size, kind, name)| {
+            let alias_of = strong_by_addr.get(&addr).filter(|target| **target != name).cloned();
+            let is_handler = matches!(kind.as_str(), "W" | "w") && is_handler_name(&name);
+            WeakSymbol {
+                name,
+                addr,
+                size,
+                kind,
+                alias_of,
+                is_handler,
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| b.is_handler.cmp(&a.is_handler).then_with(|| a.name.cmp(&b.name)));
+    result
+}
+
+fn is_handler_name(name: &str) -> bool {
+    name.ends_with("Handler") || name.ends_with("_IRQHandler") || name.contains("Callback")
+}
+
+fn parse_common_symbols(contents: &str) -> Vec<CommonSymbol> {
+    let mut result = Vec::new();
+    let mut in_section = false;
+    let mut header_seen = false;
+    let mut pending_name: Option<String> = None;
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("Allocating common symbols") {
+            in_section = true;
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if trimmed.starts_with("Common symbol") {
+            header_seen = true;
+            continue;
+        }
+        if !header_seen {
+            continue;
+        }
+        if trimmed.is_empty() {
+            if result.is_empty() && pending_name.is_none() {
+                continue;
+            }
+            break;
+        }
+        let parts: Vec<&str> = trimmed.split_whitespace().collect();
+        let (name, rest) = if parts[0].starts_with("0x") {
+            match pending_name.take() {
+                Some(name) => (name, &parts[..]),
+                None => continue,
+            }
+        } else if parts.len() == 1 {
+            pending_name = Some(parts[0].to_string());
+            continue;
+        } else {
+            (parts[0].to_string(), &parts[1..])
+        };
+        if rest.len() < 2 {
+            continue;
+        }
+        result.push(CommonSymbol {
+            name,
+            size: parse_hex_or_dec(rest[0]),
+            object: rest[1..].join(" "),
+        });
+    }
+
+    result
+}
+
+/// Object files and archives named by `LOAD` lines in the MAP and found on disk, plus the
+/// archive members the link actually pulled in.
+pub fn map_load_inputs(elf_path: &str, map_path: &str, contents: &str) -> (Vec<String>, HashSet<String>) {
+    let search_dirs = object_search_dirs(elf_path, map_path);
+    (collect_load_inputs(contents, &search_dirs), collect_linked_inputs(contents))
+}
+
+fn object_search_dirs(elf_path: &str, map_path: &str) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    for path in [map_path, elf_path] {
+        if let Some(parent) = Path::new(path.trim()).parent() {
+            let dir = parent.to_path_buf();
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+    }
+    dirs
+}
+
+fn collect_load_inputs(contents: &str, search_dirs: &[PathBuf]) -> Vec<String> {
+    let mut inputs = Vec::new();
+    let mut seen = HashSet::new();
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        let path = match trimmed.strip_prefix("LOAD ") {
+            Some(path) => path.trim(),
+            None => continue,
+        };
+        if !(path.ends_with(".o") || path.ends_with(".obj") || path.ends_with(".a")) {
+            continue;
+        }
+        let candidate = Path::new(path);
+        let resolved = if candidate.is_absolute() {
+            Some(candidate.to_path_buf()).filter(|p| p.is_file())
+        } else {
+            search_dirs.iter().map(|dir| dir.join(candidate)).find(|p| p.is_file())
+        };
+        if let Some(resolved) = resolved {
+            let value = resolved.to_string_lossy().to_string();
+            if seen.insert(value.clone()) {
+                inputs.push(value);
+            }
+        }
+    }
+    inputs
+}
+
+/// Archive members only count when the MAP shows them being pulled into the link.
+fn collect_linked_inputs(contents: &str) -> HashSet<String> {
+    let mut linked = HashSet::new();
+    for line in contents.lines() {
+        let file = match line.split_whitespace().last() {
+            Some(file) => file,
+            None => continue,
+        };
+        if let Some(start) = file.find('(') {
+            if file.ends_with(')') && file[..start].ends_with(".a") {
+                linked.insert(object_label(&file[..start], Some(&file[start + 1..file.len() - 1])));
+            }
+        }
+    }
+    linked
+}
+
+pub fn is_linked_input(object: &str, linked: &HashSet<String>) -> bool {
+    !object.contains('(') || linked.contains(object)
+}
+
+/// Objects are named by file name, archive members as `libfoo.a(member.o)`, the way the
+/// MAP names them.
+pub fn object_label(path: &str, member: Option<&str>) -> String {
+    let base = Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string());
+    match member {
+        Some(member) => format!("{}({})", base, member),
+        None => base,
+    }
+}
+
+/// Parses `nm -A -S` output, keeping defined symbols only.
+fn parse_object_symbols(output: &str) -> Vec<ObjectSymbol> {
+    let mut result = Vec::new();
+    for line in output.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 3 {
+            continue;
+        }
+        let name = parts[parts.len() - 1];
+        let kind = parts[parts.len() - 2];
+        if kind == "U" || kind == "u" {
+            continue;
+        }
+        let (file, size) = match parts.len() {
+            3 => (parts[0], 0),
+            _ => (parts[0], u64::from_str_radix(parts[1], 16).unwrap_or(0)),
+        };
+        let file = match file.rsplit_once(':') {
+            Some((file, _)) => file,
+            None => continue,
+        };
+        let object = match file.rsplit_once(':') {
+            Some((archive, member)) if archive.ends_with(".a") => object_label(archive, Some(member)),
+            _ => object_label(file, None),
+        };
+        result.push(ObjectSymbol {
+            object,
+            name: name.to_string(),
+            kind: kind.to_string(),
+            size,
+        });
+    }
+    result
+}
@@ -0,0 +1,440 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::analyzer::MemoryRegion;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(u64),
+    Ident(String),
+    Sym(&'static str),
+}
+
+struct Region {
+    origin: u64,
+    length: u64,
+}
+
+/// Nesting limit for INCLUDE, which also ends include cycles.
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+pub fn parse_linker_script_file(path: &str) -> Result<Vec<MemoryRegion>, String> {
+    let contents = read_with_includes(Path::new(path), 0)?;
+    parse_linker_script_regions(&contents)
+}
+
+/// Splices `INCLUDE file` lines in place, resolved against the including script's directory,
+/// so vendor scripts that keep MEMORY in a separate file still parse. Includes that ld would
+/// only find through `-L` search paths are left out.
+fn read_with_includes(path: &Path, depth: usize) -> Result<String, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read linker script {}: {}", path.display(), e))?;
+    let mut expanded = String::with_capacity(contents.len());
+    for line in strip_comments(&contents).lines() {
+        let include = line
+            .trim()
+            .strip_prefix("INCLUDE")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .map(|rest| rest.trim().trim_end_matches(';').trim().trim_matches('"'))
+            .filter(|name| !name.is_empty());
+        match include {
+            Some(name) => {
+                let included = path.parent().unwrap_or_else(|| Path::new(".")).join(name);
+                if depth < MAX_INCLUDE_DEPTH && included.is_file() {
+                    expanded.push_str(&read_with_includes(&included, depth + 1)?);
+                }
+            }
+            None => expanded.push_str(line),
+        }
+        expanded.push('\n');
+    }
+    Ok(expanded)
+}
+
+/// Reads the MEMORY block of a GNU ld script. Top-level `NAME = expr;` assignments are
+/// evaluated first so regions may refer to them, as may later regions to earlier ones.
+pub fn parse_linker_script_regions(contents: &str) -> Result<Vec<MemoryRegion>, String> {
+    let tokens = tokenize(&strip_comments(contents));
+    let mut symbols: HashMap<String, u64> = HashMap::new();
+    let mut regions: Vec<(String, Region)> = Vec::new();
+
+    let mut depth = 0usize;
+    let mut idx = 0;
+    while idx < tokens.len() {
+        match &tokens[idx] {
+            Token::Sym("{") => depth += 1,
+            Token::Sym("}") => depth = depth.saturating_sub(1),
+            Token::Ident(name) if depth == 0 && name == "MEMORY" => {
+                if tokens.get(idx + 1) != Some(&Token::Sym("{")) {
+                    return Err("Linker script MEMORY block is missing '{'.".to_string());
+                }
+                idx = parse_memory_block(&tokens, idx + 2, &symbols, &mut regions)?;
+                continue;
+            }
+            Token::Ident(name) if depth == 0 && tokens.get(idx + 1) == Some(&Token::Sym("=")) => {
+                let mut parser = ExprParser {
+                    tokens: &tokens,
+                    pos: idx + 2,
+                    symbols: &symbols,
+                    regions: &regions,
+                };
+                let value = parser.parse_expr();
+                idx = parser.pos;
+                if let Ok(value) = value {
+                    symbols.insert(name.clone(), value);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+
+    if regions.is_empty() {
+        return Err("Linker script does not define any MEMORY regions.".to_string());
+    }
+
+    Ok(regions
+        .into_iter()
+        .map(|(name, region)| MemoryRegion {
+            name,
+            origin: format!("0x{:08x}", region.origin),
+            length: region.length,
+            used: None,
+            padding_bytes: None,
+            sources: Vec::new(),
+            usage_source: None,
+        })
+        .collect())
+}
+
+fn parse_memory_block(
+    tokens: &[Token],
+    mut idx: usize,
+    symbols: &HashMap<String, u64>,
+    regions: &mut Vec<(String, Region)>,
+) -> Result<usize, String> {
+    loop {
+        let name = match tokens.get(idx) {
+            Some(Token::Sym("}")) => return Ok(idx + 1),
+            Some(Token::Ident(name)) => name.clone(),
+            Some(other) => return Err(format!("Unexpected token in MEMORY block: {:?}", other)),
+            None => return Err("Linker script MEMORY block is not closed.".to_string()),
+        };
+        idx += 1;
+        if tokens.get(idx) == Some(&Token::Sym("(")) {
+            while idx < tokens.len() && tokens[idx] != Token::Sym(")") {
+                idx += 1;
+            }
+            idx += 1;
+        }
+        if tokens.get(idx) != Some(&Token::Sym(":")) {
+            return Err(format!("Expected ':' after MEMORY region {}.", name));
+        }
+        idx += 1;
+
+        let mut origin = None;
+        let mut length = None;
+        while origin.is_none() || length.is_none() {
+            let key = match tokens.get(idx) {
+                Some(Token::Ident(key)) => key.to_ascii_lowercase(),
+                _ => return Err(format!("Expected ORIGIN/LENGTH for MEMORY region {}.", name)),
+            };
+            if tokens.get(idx + 1) != Some(&Token::Sym("=")) {
+                return Err(format!("Expected '=' after {} in MEMORY region {}.", key, name));
+            }
+            let mut parser = ExprParser {
+                tokens,
+                pos: idx + 2,
+                symbols,
+                regions,
+            };
+            let value = parser
+                .parse_expr()
+                .map_err(|e| format!("MEMORY region {}: {}", name, e))?;
+            idx = parser.pos;
+            match key.as_str() {
+                "origin" | "org" | "o" => origin = Some(value),
+                "length" | "len" | "l" => length = Some(value),
+                _ => return Err(format!("Unknown MEMORY attribute {} in region {}.", key, name)),
+            }
+            if tokens.get(idx) == Some(&Token::Sym(",")) {
+                idx += 1;
+            }
+        }
+
+        regions.push((
+            name,
+            Region {
+                origin: origin.unwrap_or(0),
+                length: length.unwrap_or(0),
+            },
+        ));
+    }
+}
+
+struct ExprParser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    symbols: &'a HashMap<String, u64>,
+    regions: &'a [(String, Region)],
+}
+
+impl ExprParser<'_> {
+    fn parse_expr(&mut self) -> Result<u64, String> {
+        self.parse_binary(0)
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<u64, String> {
+        const LEVELS: [&[&str]; 5] = [&["|"], &["&"], &["<<", ">>"], &["+", "-"], &["*", "/", "%"]];
+        if level == LEVELS.len() {
+            return self.parse_unary();
+        }
+        let mut value = self.parse_binary(level + 1)?;
+        while let Some(Token::Sym(op)) = self.tokens.get(self.pos) {
+            if !LEVELS[level].contains(op) {
+                break;
+            }
+            let op = *op;
+            self.pos += 1;
+            let rhs = self.parse_binary(level + 1)?;
+            value = match op {
+                "|" => value | rhs,
+                "&" => value & rhs,
+                "<<" => value.checked_shl(rhs as u32).unwrap_or(0),
+                ">>" => value.checked_shr(rhs as u32).unwrap_or(0),
+                "+" => value.wrapping_add(rhs),
+                "-" => value.wrapping_sub(rhs),
+                "*" => value.wrapping_mul(rhs),
+                "/" => value.checked_div(rhs).ok_or_else(|| "division by zero".to_string())?,
+                _ => value.checked_rem(rhs).ok_or_else(|| "division by zero".to_string())?,
+            };
+        }
+        Ok(value)
+    }
+
+    fn parse_unary(&mut self) -> Result<u64, String> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Sym("-")) => {
+                self.pos += 1;
+                Ok(self.parse_unary()?.wrapping_neg())
+            }
+            Some(Token::Sym("~")) => {
+                self.pos += 1;
+                Ok(!self.parse_unary()?)
+            }
+            _ => self.parse_primary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<u64, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| "unexpected end of expression".to_string())?;
+        self.pos += 1;
+        match token {
+            Token::Num(value) => Ok(value),
+            Token::Sym("(") => {
+                let value = self.parse_expr()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Token::Ident(name) if self.tokens.get(self.pos) == Some(&Token::Sym("(")) => {
+                self.pos += 1;
+                let arg = match self.tokens.get(self.pos) {
+                    Some(Token::Ident(arg)) => arg.clone(),
+                    _ => return Err(format!("expected region name in {}()", name)),
+                };
+                self.pos += 1;
+                self.expect(")")?;
+                let region = self
+                    .regions
+                    .iter()
+                    .find(|(region_name, _)| *region_name == arg)
+                    .map(|(_, region)| region)
+                    .ok_or_else(|| format!("unknown region {} in {}()", arg, name))?;
+                match name.to_ascii_uppercase().as_str() {
+                    "ORIGIN" => Ok(region.origin),
+                    "LENGTH" => Ok(region.length),
+                    _ => Err(format!("unsupported function {}()", name)),
+                }
+            }
+            Token::Ident(name) => self
+                .symbols
+                .get(&name)
+                .copied()
+                .ok_or_else(|| format!("undefined symbol {}", name)),
+            Token::Sym(sym) => Err(format!("unexpected '{}'", sym)),
+        }
+    }
+
+    fn expect(&mut self, sym: &'static str) -> Result<(), String> {
+        if self.tokens.get(self.pos) == Some(&Token::Sym(sym)) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!("expected '{}'", sym))
+        }
+    }
+}
+
+fn strip_comments(contents: &str) -> String {
+    let mut result = String::with_capacity(contents.len());
+    let mut rest = contents;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        match rest[start + 2..].find("*/") {
+            Some(end) => {
+                result.push(' ');
+                rest = &rest[start + 2 + end + 2..];
+            }
+            None => {
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn tokenize(contents: &str) -> Vec<Token> {
+    const SYMBOLS: [&str; 18] = [
+        "<<", ">>", "{", "}", "(", ")", ":", ";", ",", "=", "+", "-", "*", "/", "%", "&", "|", "~",
+    ];
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = contents.chars().collect();
+    let mut idx = 0;
+    while idx < chars.len() {
+        let ch = chars[idx];
+        if ch.is_whitespace() {
+            idx += 1;
+            continue;
+        }
+        if ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '$') {
+            let start = idx;
+            while idx < chars.len() && (chars[idx].is_ascii_alphanumeric() || matches!(chars[idx], '_' | '.' | '$')) {
+                idx += 1;
+            }
+            let word: String = chars[start..idx].iter().collect();
+            match parse_script_number(&word).filter(|_| ch.is_ascii_digit()) {
+                Some(value) => tokens.push(Token::Num(value)),
+                None => tokens.push(Token::Ident(word)),
+            }
+            continue;
+        }
+        let rest: String = chars[idx..(idx + 2).min(chars.len())].iter().collect();
+        match SYMBOLS.iter().find(|sym| rest.starts_with(**sym)) {
+            Some(sym) => {
+                tokens.push(Token::Sym(sym));
+                idx += sym.len();
+            }
+            // Anything else (strings, `?:`, `!=`) only appears outside MEMORY; skip it.
+            None => idx += 1,
+        }
+    }
+    tokens
+}
+
+fn parse_script_number(word: &str) -> Option<u64> {
+    let lower = word.to_ascii_lowercase();
+    let (digits, multiplier) = if let Some(hex) = lower.strip_prefix("0x") {
+        return u64::from_str_radix(hex, 16).ok();
+    } else if let Some(value) = lower.strip_suffix('k') {
+        (value, 1024)
+    } else if let Some(value) = lower.strip_suffix('m') {
+        (value, 1024 * 1024)
+    } else {
+        (lower.as_str(), 1)
+    };
+    let value = if digits.len() > 1 && digits.starts_with('0') {
+        u64::from_str_radix(&digits[1..], 8).ok()?
+    } else {
+        digits.parse::<u64>().ok()?
+    };
+    Some(value * multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn region<'a>(regions: &'a [MemoryRegion], name: &str) -> &'a MemoryRegion {
+        regions.iter().find(|region| region.name == name).unwrap()
+    }
+
+    fn script_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("linker-script-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn evaluates_suffixes_symbols_and_region_functions() {
+        let script = "
+            _Min_Stack_Size = 0x400; /* not part of MEMORY */
+            FLASH_SIZE = 512K;
+            MEMORY
+            {
+              FLASH (rx)  : ORIGIN = 0x08000000, LENGTH = FLASH_SIZE - 16K
+              RAM (xrw)   : ORIGIN = 0x20000000, LENGTH = 128K
+              CCM (rw)    : org = ORIGIN(RAM) + LENGTH(RAM), len = 1M / 16
+              BOOT        : ORIGIN = 0x08000000 | (1 << 4), LENGTH = 4 * (2 + 2)
+            }
+            SECTIONS { .text : { *(.text) } > FLASH }
+        ";
+        let regions = parse_linker_script_regions(script).unwrap();
+        assert_eq!(regions.len(), 4);
+        assert_eq!(region(&regions, "FLASH").origin, "0x08000000");
+        assert_eq!(region(&regions, "FLASH").length, 496 * 1024);
+        assert_eq!(region(&regions, "RAM").length, 128 * 1024);
+        assert_eq!(region(&regions, "CCM").origin, "0x20020000");
+        assert_eq!(region(&regions, "CCM").length, 64 * 1024);
+        assert_eq!(region(&regions, "BOOT").origin, "0x08000010");
+        assert_eq!(region(&regions, "BOOT").length, 16);
+    }
+
+    #[test]
+    fn reads_script_numbers() {
+        assert_eq!(parse_script_number("0x1000"), Some(0x1000));
+        assert_eq!(parse_script_number("64K"), Some(64 * 1024));
+        assert_eq!(parse_script_number("2M"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_script_number("010"), Some(8));
+        assert_eq!(parse_script_number("12x"), None);
+    }
+
+    #[test]
+    fn reports_bad_memory_blocks() {
+        assert!(parse_linker_script_regions("SECTIONS { }").is_err());
+        let err = parse_linker_script_regions("MEMORY { RAM : ORIGIN = 0, LENGTH = SIZE }").unwrap_err();
+        assert!(err.contains("undefined symbol SIZE"), "{}", err);
+        let err = parse_linker_script_regions("MEMORY { RAM : ORIGIN = 0, LENGTH = 4 / 0 }").unwrap_err();
+        assert!(err.contains("division by zero"), "{}", err);
+    }
+
+    #[test]
+    fn follows_includes_relative_to_the_script() {
+        let dir = script_dir("include");
+        fs::write(dir.join("memory.ld"), "MEMORY { FLASH : ORIGIN = 0x08000000, LENGTH = 256K }\n").unwrap();
+        fs::write(dir.join("app.ld"), "INCLUDE memory.ld\nINCLUDE missing.ld\nSECTIONS { }\n").unwrap();
+        let regions = parse_linker_script_file(dir.join("app.ld").to_str().unwrap()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].length, 256 * 1024);
+    }
+
+    #[test]
+    fn stops_include_cycles_at_the_depth_limit() {
+        let dir = script_dir("cycle");
+        fs::write(dir.join("a.ld"), "INCLUDE b.ld\n").unwrap();
+        fs::write(dir.join("b.ld"), "INCLUDE a.ld\n").unwrap();
+        fs::write(dir.join("app.ld"), "INCLUDE a.ld\nMEMORY { RAM : ORIGIN = 0x20000000, LENGTH = 20K }\n").unwrap();
+        let regions = parse_linker_script_file(dir.join("app.ld").to_str().unwrap()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].length, 20 * 1024);
+    }
+}
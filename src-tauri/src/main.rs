@@ -2,9 +2,31 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod font_pipeline;
+mod forecast;
 mod analyzer;
+mod build_info;
+mod disasm;
+mod dwarf;
+mod elf_library;
+mod elf_symbols;
+mod firmware_image;
 mod fs_utils;
+mod fuzzy;
+mod identity;
+mod image_export;
+mod layout;
+mod linkage;
+mod linker_script;
+mod mapping_symbols;
+mod multi_image;
+mod ram_dump;
+mod reservations;
+mod section_rules;
 mod settings;
+mod struct_layout;
+mod svd;
+mod symbol_index;
+mod symbol_query;
 mod system_fonts;
 mod toolchain;
 
@@ -21,9 +43,24 @@ fn main() {
             system_fonts::list_system_fonts,
             toolchain::detect_toolchain,
             analyzer::analyze_firmware,
+            analyzer::list_sessions,
+            analyzer::close_session,
+            analyzer::get_session,
             analyzer::list_symbols,
             analyzer::list_symbol_facets,
-            analyzer::lookup_pc
+            analyzer::lookup_pc,
+            analyzer::lookup_data_address,
+            disasm::disassemble_symbol,
+            elf_library::list_elf_library,
+            elf_library::register_elf_library,
+            elf_library::remove_elf_library,
+            elf_library::match_crash_log,
+            firmware_image::inspect_image,
+            image_export::export_flash_image,
+            multi_image::analyze_multi_image,
+            ram_dump::analyze_ram_dump,
+            ram_dump::inspect_variable,
+            struct_layout::analyze_struct_layout
         ])
         .run(tauri::generate_context!())
         .expect("Failed to run Tauri application");
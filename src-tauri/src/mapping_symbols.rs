@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{parse_hex_str, Finding, SymbolInfo};
+
+const REPORT_LIMIT: usize = 50;
+const POOL_MIN_BYTES: u64 = 128;
+const POOL_MIN_PERCENT: u64 = 25;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CodeDataReport {
+    pub mapping_symbols: usize,
+    pub code_bytes: u64,
+    pub data_bytes: u64,
+    pub functions: Vec<FunctionSplit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionSplit {
+    pub name: String,
+    pub addr: String,
+    pub size: u64,
+    pub code_bytes: u64,
+    pub data_bytes: u64,
+}
+
+/// Reads ARM/AArch64 mapping symbols (`$a`, `$t`, `$x` start code, `$d` starts data) from
+/// `nm --special-syms -n` output, sorted by address.
+pub fn parse_mapping_symbols(nm_output: &str) -> Vec<(u64, bool)> {
+    let mut marks: Vec<(u64, bool)> = nm_output
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let (addr, name) = (parts.first()?, parts.last()?);
+            let kind = name.strip_prefix('$')?.split('.').next()?;
+            let is_data = match kind {
+                "d" => true,
+                "a" | "t" | "x" => false,
+                _ => return None,
+            };
+            Some((u64::from_str_radix(addr, 16).ok()?, is_data))
+        })
+        .collect();
+    marks.sort_by_key(|(addr, _)| *addr);
+    marks
+}
+
+/// Sets `data_bytes` on every function symbol covered by mapping symbols and returns the
+/// functions with the most embedded data.
+pub fn apply_code_data_split(symbols: &mut [SymbolInfo], marks: &[(u64, bool)]) -> CodeDataReport {
+    let mut report = CodeDataReport {
+        mapping_symbols: marks.len(),
+        ..Default::default()
+    };
+    if marks.is_empty() {
+        return report;
+    }
+    let mut functions = Vec::new();
+    for symbol in symbols.iter_mut() {
+        if !matches!(symbol.kind.as_str(), "T" | "t" | "W" | "w") || symbol.size == 0 {
+            continue;
+        }
+        let start = match symbol.addr.as_deref().and_then(parse_hex_str) {
+            Some(addr) => addr & !1,
+            None => continue,
+        };
+        let end = start + symbol.size;
+        let first = marks.partition_point(|(addr, _)| *addr <= start);
+        let mut in_data = first.checked_sub(1).map(|idx| marks[idx].1).unwrap_or(false);
+        let mut cursor = start;
+        let mut data_bytes = 0;
+        for (addr, is_data) in marks[first..].iter().take_while(|(addr, _)| *addr < end) {
+            if in_data {
+                data_bytes += addr - cursor;
+            }
+            cursor = *addr;
+            in_data = *is_data;
+        }
+        if in_data {
+            data_bytes += end - cursor;
+        }
+        symbol.data_bytes = Some(data_bytes);
+        report.code_bytes += symbol.size - data_bytes;
+        report.data_bytes += data_bytes;
+        if data_bytes > 0 {
+            functions.push(FunctionSplit {
+                name: symbol.name.clone(),
+                addr: format!("0x{:08x}", start),
+                size: symbol.size,
+                code_bytes: symbol.size - data_bytes,
+                data_bytes,
+            });
+        }
+    }
+    functions.sort_by_key(|f| std::cmp::Reverse(f.data_bytes));
+    functions.truncate(REPORT_LIMIT);
+    report.functions = functions;
+    report
+}
+
+pub fn compute_code_data_findings(report: &CodeDataReport) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let heavy: Vec<&FunctionSplit> = report
+        .functions
+        .iter()
+        .filter(|f| f.data_bytes >= POOL_MIN_BYTES && f.data_bytes * 100 >= f.size * POOL_MIN_PERCENT)
+        .collect();
+    if !heavy.is_empty() {
+        findings.push(Finding {
+            id: "LITERAL_POOL_HEAVY".to_string(),
+            severity: "info".to_string(),
+            value: heavy.iter().map(|f| f.data_bytes).sum(),
+            items: heavy
+                .iter()
+                .take(5)
+                .map(|f| format!("{}: {} of {} B data", f.name, f.data_bytes, f.size))
+                .collect(),
+        });
+    }
+    findings
+}
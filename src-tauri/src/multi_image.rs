@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{parse_hex_or_dec, parse_hex_str, parse_pc_address, AppState, Finding, MemoryRegion};
+use crate::identity::read_firmware_identity;
+use crate::layout::{LayoutEntry, LayoutOverlap, LayoutRegion};
+use crate::linker_script::parse_linker_script_file;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiImageParams {
+    pub images: Vec<ImageSlotParams>,
+    /// Shared memory layout; defaults to the regions of the session's analysis.
+    pub linker_script_path: Option<String>,
+    pub session_id: Option<String>,
+}
+
+/// A slot is either a named region of the shared layout or an explicit origin and length.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSlotParams {
+    pub name: String,
+    pub elf_path: String,
+    pub slot_region: Option<String>,
+    pub slot_origin: Option<String>,
+    pub slot_length: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiImageReport {
+    pub images: Vec<ImageFootprint>,
+    pub regions: Vec<LayoutRegion>,
+    pub overlaps: Vec<LayoutOverlap>,
+    pub free_bytes: u64,
+    pub findings: Vec<Finding>,
+}
+
+/// Flash footprint of one ELF: the file contents of its loadable segments at their
+/// physical (load) addresses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageFootprint {
+    pub name: String,
+    pub elf_path: String,
+    pub build_id: Option<String>,
+    pub start: String,
+    pub end: String,
+    pub load_bytes: u64,
+    pub slot: Option<SlotUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlotUsage {
+    pub name: String,
+    pub origin: String,
+    pub length: u64,
+    pub used: u64,
+    pub usage_percent: f64,
+    pub outside_bytes: u64,
+}
+
+struct Span {
+    image: usize,
+    start: u64,
+    end: u64,
+}
+
+#[tauri::command]
+pub fn analyze_multi_image(state: tauri::State<'_, AppState>, params: MultiImageParams) -> Result<MultiImageReport, String> {
+    if params.images.is_empty() {
+        return Err("At least one image is required.".to_string());
+    }
+    let regions = match params.linker_script_path.as_deref().map(str::trim).filter(|p| !p.is_empty()) {
+        Some(path) => parse_linker_script_file(path)?,
+        None => match params.session_id.as_deref() {
+            Some(id) => state
+                .session(id)?
+                .analysis
+                .lock()
+                .map_err(|_| "Failed to read analysis state.".to_string())?
+                .as_ref()
+                .map(|analysis| analysis.summary.memory_regions.clone())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        },
+    };
+
+    let mut images = Vec::new();
+    let mut spans: Vec<Span> = Vec::new();
+    for (index, image) in params.images.iter().enumerate() {
+        let identity = read_firmware_identity(image.elf_path.trim())?;
+        let image_spans: Vec<Span> = identity
+            .segments
+            .iter()
+            .filter_map(|segment| {
+                let start = parse_hex_str(&segment.paddr)?;
+                Some(Span {
+                    image: index,
+                    start,
+                    end: start + segment.filesz,
+                })
+            })
+            .collect();
+        let slot = resolve_slot(image, &regions)?.map(|(name, origin, length)| {
+            let slot_end = origin + length;
+            let used: u64 = image_spans
+                .iter()
+                .map(|span| span.end.min(slot_end).saturating_sub(span.start.max(origin)))
+                .sum();
+            let total: u64 = image_spans.iter().map(|span| span.end - span.start).sum();
+            SlotUsage {
+                name,
+                origin: format!("0x{:08x}", origin),
+                length,
+                used,
+                usage_percent: if length > 0 { used as f64 * 100.0 / length as f64 } else { 0.0 },
+                outside_bytes: total - used,
+            }
+        });
+        images.push(ImageFootprint {
+            name: image.name.clone(),
+            elf_path: image.elf_path.trim().to_string(),
+            build_id: identity.build_id,
+            start: format!("0x{:08x}", image_spans.iter().map(|s| s.start).min().unwrap_or(0)),
+            end: format!("0x{:08x}", image_spans.iter().map(|s| s.end).max().unwrap_or(0)),
+            load_bytes: image_spans.iter().map(|span| span.end - span.start).sum(),
+            slot,
+        });
+        spans.extend(image_spans);
+    }
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end)));
+
+    let overlaps = find_image_overlaps(&spans, &images);
+    let mut free_bytes = 0;
+    let layout_regions: Vec<LayoutRegion> = regions
+        .iter()
+        .filter_map(|region| {
+            let start = parse_hex_or_dec(&region.origin);
+            let end = start.saturating_add(region.length);
+            let inside: Vec<&Span> = spans.iter().filter(|span| span.start < end && span.end > start).collect();
+            // Regions no image loads into (RAM, usually) say nothing about free flash.
+            if inside.is_empty() {
+                return None;
+            }
+            let entries = lay_out_images(start, end, &inside, &images);
+            free_bytes += entries.iter().filter(|entry| entry.kind == "free").map(|entry| entry.size).sum::<u64>();
+            Some(LayoutRegion {
+                name: region.name.clone(),
+                start: format!("0x{:08x}", start),
+                end: format!("0x{:08x}", end),
+                length: region.length,
+                entries,
+            })
+        })
+        .collect();
+    let findings = compute_multi_image_findings(&images, &overlaps);
+
+    Ok(MultiImageReport {
+        images,
+        regions: layout_regions,
+        overlaps,
+        free_bytes,
+        findings,
+    })
+}
+
+fn resolve_slot(image: &ImageSlotParams, regions: &[MemoryRegion]) -> Result<Option<(String, u64, u64)>, String> {
+    if let Some(name) = image.slot_region.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+        let region = regions
+            .iter()
+            .find(|region| region.name == name)
+            .ok_or_else(|| format!("Slot region {} for image {} not found.", name, image.name))?;
+        return Ok(Some((region.name.clone(), parse_hex_or_dec(&region.origin), region.length)));
+    }
+    match (image.slot_origin.as_deref().map(str::trim).filter(|o| !o.is_empty()), image.slot_length) {
+        (Some(origin), Some(length)) => {
+            let origin = parse_pc_address(origin).map_err(|e| format!("Invalid slot origin for image {}: {}", image.name, e))?;
+            Ok(Some((format!("{} slot", image.name), origin, length)))
+        }
+        (None, None) => Ok(None),
+        _ => Err(format!("Slot for image {} needs both an origin and a length.", image.name)),
+    }
+}
+
+fn find_image_overlaps(sorted: &[Span], images: &[ImageFootprint]) -> Vec<LayoutOverlap> {
+    let mut overlaps = Vec::new();
+    for (idx, span) in sorted.iter().enumerate() {
+        for other in sorted[idx + 1..].iter().take_while(|other| other.start < span.end) {
+            if other.image == span.image {
+                continue;
+            }
+            let end = span.end.min(other.end);
+            overlaps.push(LayoutOverlap {
+                first: images[span.image].name.clone(),
+                second: images[other.image].name.clone(),
+                start: format!("0x{:08x}", other.start),
+                end: format!("0x{:08x}", end),
+                size: end - other.start,
+            });
+        }
+    }
+    overlaps
+}
+
+fn lay_out_images(start: u64, end: u64, spans: &[&Span], images: &[ImageFootprint]) -> Vec<LayoutEntry> {
+    let entry = |kind: &str, name: &str, from: u64, to: u64| LayoutEntry {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        start: format!("0x{:08x}", from),
+        end: format!("0x{:08x}", to),
+        size: to.saturating_sub(from),
+    };
+    let mut entries: Vec<LayoutEntry> = Vec::new();
+    let mut cursor = start;
+    for span in spans {
+        let from = span.start.max(start);
+        let to = span.end.min(end);
+        if from > cursor {
+            entries.push(entry("free", "free", cursor, from));
+        }
+        let name = &images[span.image].name;
+        // Adjacent segments of the same image read as one block.
+        match entries.last_mut() {
+            Some(last) if last.kind == "image" && last.name == *name && from <= cursor => {
+                last.end = format!("0x{:08x}", to.max(cursor));
+                last.size = to.max(cursor) - parse_hex_str(&last.start).unwrap_or(from);
+            }
+            _ => entries.push(entry("image", name, from, to)),
+        }
+        cursor = cursor.max(to);
+    }
+    if cursor < end {
+        entries.push(entry("free", "free", cursor, end));
+    }
+    entries
+}
+
+fn compute_multi_image_findings(images: &[ImageFootprint], overlaps: &[LayoutOverlap]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !overlaps.is_empty() {
+        findings.push(Finding {
+            id: "IMAGE_OVERLAP".to_string(),
+            severity: "warn".to_string(),
+            value: overlaps.iter().map(|overlap| overlap.size).sum(),
+            items: overlaps
+                .iter()
+                .take(5)
+                .map(|overlap| format!("{} / {}: {}..{} ({} B)", overlap.first, overlap.second, overlap.start, overlap.end, overlap.size))
+                .collect(),
+        });
+    }
+    let outside: Vec<(&String, &SlotUsage)> = images
+        .iter()
+        .filter_map(|image| Some((&image.name, image.slot.as_ref()?)))
+        .filter(|(_, slot)| slot.outside_bytes > 0)
+        .collect();
+    if !outside.is_empty() {
+        findings.push(Finding {
+            id: "IMAGE_OUTSIDE_SLOT".to_string(),
+            severity: "warn".to_string(),
+            value: outside.iter().map(|(_, slot)| slot.outside_bytes).sum(),
+            items: outside
+                .iter()
+                .map(|(name, slot)| format!("{}: {} B outside {}", name, slot.outside_bytes, slot.name))
+                .collect(),
+        });
+    }
+    findings
+}
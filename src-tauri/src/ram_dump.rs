@@ -0,0 +1,529 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::analyzer::{is_linker_symbol_name, parse_hex_str, parse_pc_address, AppState, Finding, SymbolInfo};
+use crate::dwarf::{dwarf_for_session, DwarfIndex, TypeKind};
+
+const DEFAULT_FILL_PATTERN: &str = "0xA5A5A5A5";
+const DEFAULT_WARN_PERCENT: f64 = 90.0;
+const MIN_TASK_STACK: u64 = 64;
+const DEFAULT_MAX_ELEMENTS: usize = 64;
+const MAX_VALUE_NODES: usize = 5000;
+const MAX_VALUE_DEPTH: usize = 12;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RamDumpParams {
+    pub dump_path: String,
+    pub base_address: String,
+    pub fill_pattern: Option<String>,
+    pub stack_symbols: Option<Vec<String>>,
+    pub warn_percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RamDumpReport {
+    pub dump_path: String,
+    pub base_address: String,
+    pub dump_size: u64,
+    pub fill_pattern: String,
+    pub stacks: Vec<StackUsage>,
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StackUsage {
+    pub name: String,
+    pub start: String,
+    pub end: String,
+    pub size: u64,
+    pub used: Option<u64>,
+    pub unused: Option<u64>,
+    pub usage_percent: Option<f64>,
+    pub in_dump: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectVariableParams {
+    pub dump_path: String,
+    pub base_address: String,
+    pub name: String,
+    pub max_elements: Option<usize>,
+}
+
+/// One decoded value. Aggregates carry their members or elements as children; `value`
+/// is `None` for aggregates and for bytes that are not covered by the dump.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueNode {
+    pub name: String,
+    pub type_name: String,
+    pub addr: String,
+    pub size: u64,
+    pub value: Option<String>,
+    pub children: Vec<ValueNode>,
+}
+
+/// A raw memory image loaded at a known base address.
+pub struct RamDump {
+    pub base: u64,
+    pub bytes: Vec<u8>,
+}
+
+impl RamDump {
+    pub fn load(path: &str, base_address: &str) -> Result<Self, String> {
+        let path = path.trim();
+        if path.is_empty() {
+            return Err("RAM dump path is required.".to_string());
+        }
+        let base = parse_pc_address(base_address).map_err(|e| format!("Invalid RAM dump base address: {}", e))?;
+        let bytes = fs::read(path).map_err(|e| format!("Failed to read RAM dump {}: {}", path, e))?;
+        Ok(Self { base, bytes })
+    }
+
+    pub fn read(&self, addr: u64, len: u64) -> Option<&[u8]> {
+        let offset = addr.checked_sub(self.base)? as usize;
+        let end = offset.checked_add(len as usize)?;
+        self.bytes.get(offset..end)
+    }
+}
+
+#[tauri::command]
+pub fn analyze_ram_dump(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    params: RamDumpParams,
+) -> Result<RamDumpReport, String> {
+    let session = state.session(&session_id)?;
+    let dump = RamDump::load(&params.dump_path, &params.base_address)?;
+    let pattern_text = params
+        .fill_pattern
+        .as_deref()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .unwrap_or(DEFAULT_FILL_PATTERN);
+    let pattern = parse_fill_pattern(pattern_text)?;
+    let warn_percent = params.warn_percent.unwrap_or(DEFAULT_WARN_PERCENT);
+
+    let mut ranges: Vec<(String, u64, u64)> = Vec::new();
+    if let Ok(analysis) = session.analysis.lock() {
+        if let Some(report) = analysis.as_ref().map(|a| &a.stack_heap) {
+            let bottom = report.stack_bottom.as_deref().and_then(parse_hex_str);
+            let top = report.stack_top.as_deref().and_then(parse_hex_str);
+            if let (Some(bottom), Some(top)) = (bottom, top) {
+                if top > bottom {
+                    ranges.push(("main stack".to_string(), bottom, top));
+                }
+            }
+        }
+    }
+    let symbols = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+    ranges.extend(find_task_stacks(&symbols, params.stack_symbols.as_deref().unwrap_or(&[])));
+    drop(symbols);
+    if ranges.is_empty() {
+        return Err("No stacks found. Run analysis first or list stack symbols explicitly.".to_string());
+    }
+
+    // Zero-sized symbols (e.g. a `stack` label without a size) have nothing to measure.
+    let stacks: Vec<StackUsage> = ranges
+        .into_iter()
+        .filter(|(_, start, end)| end > start)
+        .map(|(name, start, end)| measure_stack(&dump, &pattern, name, start, end))
+        .collect();
+    let findings = compute_stack_findings(&stacks, warn_percent);
+
+    Ok(RamDumpReport {
+        dump_path: params.dump_path,
+        base_address: format!("0x{:08x}", dump.base),
+        dump_size: dump.bytes.len() as u64,
+        fill_pattern: pattern_text.to_string(),
+        stacks,
+        findings,
+    })
+}
+
+#[tauri::command]
+pub fn inspect_variable(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    params: InspectVariableParams,
+) -> Result<ValueNode, String> {
+    let session = state.session(&session_id)?;
+    let name = params.name.trim();
+    if name.is_empty() {
+        return Err("Variable name is required.".to_string());
+    }
+    let dump = RamDump::load(&params.dump_path, &params.base_address)?;
+    let symbols = session.symbols.lock().map_err(|_| "Failed to read symbols cache.".to_string())?;
+    let mut targets: Vec<(u64, u64, String)> = symbols
+        .iter()
+        .filter(|symbol| symbol.size > 0)
+        .filter_map(|symbol| Some((parse_hex_str(symbol.addr.as_deref()?)?, symbol.size, symbol.name.clone())))
+        .collect();
+    let fallback = symbols.iter().find(|symbol| symbol.name == name).cloned();
+    drop(symbols);
+    targets.sort();
+
+    let dwarf = dwarf_for_session(&session);
+    let variable = dwarf.as_ref().ok().and_then(|index| index.find_variable(name));
+    let (index, variable) = match (dwarf.as_ref(), variable) {
+        (Ok(index), Some(variable)) => (index, variable),
+        _ => {
+            // Without debug info the symbol table still gives address and size.
+            let symbol = fallback.ok_or_else(|| match &dwarf {
+                Err(e) => e.clone(),
+                Ok(_) => format!("Variable {} not found in DWARF or symbol table.", name),
+            })?;
+            let addr = symbol
+                .addr
+                .as_deref()
+                .and_then(parse_hex_str)
+                .ok_or_else(|| format!("Symbol {} has no address.", name))?;
+            return Ok(ValueNode {
+                name: symbol.name,
+                type_name: "?".to_string(),
+                addr: format!("0x{:08x}", addr),
+                size: symbol.size,
+                value: dump.read(addr, symbol.size).map(format_bytes),
+                children: Vec::new(),
+            });
+        }
+    };
+
+    let mut decoder = ValueDecoder {
+        index,
+        dump: &dump,
+        targets: &targets,
+        max_elements: params.max_elements.unwrap_or(DEFAULT_MAX_ELEMENTS).max(1),
+        nodes: 0,
+    };
+    Ok(decoder.decode(variable.name.clone(), variable.type_ref, variable.addr, 0))
+}
+
+struct ValueDecoder<'a> {
+    index: &'a DwarfIndex,
+    dump: &'a RamDump,
+    targets: &'a [(u64, u64, String)],
+    max_elements: usize,
+    nodes: usize,
+}
+
+impl ValueDecoder<'_> {
+    fn decode(&mut self, name: String, type_ref: Option<u64>, addr: u64, depth: usize) -> ValueNode {
+        self.nodes += 1;
+        let size = self.index.type_size(type_ref).unwrap_or(0);
+        let mut node = ValueNode {
+            name,
+            type_name: self.index.type_name(type_ref),
+            addr: format!("0x{:08x}", addr),
+            size,
+            value: None,
+            children: Vec::new(),
+        };
+        let resolved = match self.index.resolve(type_ref) {
+            Some((_, entry)) => entry,
+            None => return node,
+        };
+        if self.nodes > MAX_VALUE_NODES || depth > MAX_VALUE_DEPTH {
+            node.value = Some("...".to_string());
+            return node;
+        }
+        match &resolved.kind {
+            TypeKind::Base { encoding, size } => {
+                node.value = self.dump.read(addr, *size).map(|bytes| self.format_base(*encoding, bytes));
+            }
+            TypeKind::Enum { size, enumerators } => {
+                node.value = self.read_uint(addr, *size).map(|raw| {
+                    let value = sign_extend(raw, *size);
+                    match enumerators.iter().find(|(_, v)| *v == value) {
+                        Some((name, _)) => format!("{} ({})", name, value),
+                        None => value.to_string(),
+                    }
+                });
+            }
+            TypeKind::Pointer { size, .. } => {
+                node.value = self.read_uint(addr, *size).map(|target| self.describe_address(target));
+            }
+            TypeKind::Struct { members, .. } => {
+                for member in members {
+                    let member_addr = addr + member.offset;
+                    let name = member.name.clone().unwrap_or_else(|| "<anonymous>".to_string());
+                    let child = match member.bit_size {
+                        Some(bits) => {
+                            // Without a data bit offset the field starts at its member offset.
+                            let bit_offset = member.bit_offset.unwrap_or(member.offset * 8);
+                            self.decode_bitfield(name, member.type_ref, addr, bit_offset, bits)
+                        }
+                        None => self.decode(name, member.type_ref, member_addr, depth + 1),
+                    };
+                    node.children.push(child);
+                }
+            }
+            TypeKind::Array { element, dims } => {
+                let count = dims.first().copied().flatten().unwrap_or(0);
+                if dims.len() == 1 && self.is_char(*element) {
+                    node.value = self.dump.read(addr, count).map(format_c_string);
+                    return node;
+                }
+                node.children = self.decode_rows(String::new(), *element, dims, addr, depth).children;
+                if count as usize > self.max_elements {
+                    node.value = Some(format!("{} of {} elements shown", self.max_elements, count));
+                }
+            }
+            _ => {
+                node.value = self.dump.read(addr, size).map(format_bytes);
+            }
+        }
+        node
+    }
+
+    /// Multi-dimensional arrays decode as nested rows of the inner dimensions.
+    fn decode_rows(&mut self, name: String, element: Option<u64>, dims: &[Option<u64>], addr: u64, depth: usize) -> ValueNode {
+        let element_size = self.index.type_size(element).unwrap_or(0);
+        let row_size = dims
+            .iter()
+            .skip(1)
+            .try_fold(element_size, |acc, dim| Some(acc * (*dim)?))
+            .unwrap_or(0);
+        let count = dims.first().copied().flatten().unwrap_or(0);
+        let mut node = ValueNode {
+            name,
+            type_name: dims.iter().fold(self.index.type_name(element), |name, dim| {
+                format!("{}[{}]", name, dim.map(|d| d.to_string()).unwrap_or_default())
+            }),
+            addr: format!("0x{:08x}", addr),
+            size: row_size * count,
+            value: None,
+            children: Vec::new(),
+        };
+        for idx in 0..(count as usize).min(self.max_elements) {
+            let child_addr = addr + row_size * idx as u64;
+            let child = if dims.len() > 1 {
+                self.decode_rows(format!("[{}]", idx), element, &dims[1..], child_addr, depth + 1)
+            } else {
+                self.decode(format!("[{}]", idx), element, child_addr, depth + 1)
+            };
+            node.children.push(child);
+        }
+        node
+    }
+
+    /// `bit_offset` counts from the start of the containing struct.
+    fn decode_bitfield(&mut self, name: String, type_ref: Option<u64>, struct_addr: u64, bit_offset: u64, bits: u64) -> ValueNode {
+        self.nodes += 1;
+        let first_byte = bit_offset / 8;
+        let span = (bit_offset % 8 + bits).div_ceil(8);
+        let value = self.dump.read(struct_addr + first_byte, span).and_then(|bytes| {
+            if bits == 0 || bits > 64 || span > 8 {
+                return None;
+            }
+            let raw = bytes_to_uint(bytes, self.index.big_endian);
+            let shift = if self.index.big_endian {
+                span * 8 - bit_offset % 8 - bits
+            } else {
+                bit_offset % 8
+            };
+            let mask = if bits == 64 { u64::MAX } else { (1u64 << bits) - 1 };
+            Some((raw >> shift) & mask)
+        });
+        ValueNode {
+            name,
+            type_name: format!("{} : {}", self.index.type_name(type_ref), bits),
+            addr: format!("0x{:08x}", struct_addr + first_byte),
+            size: span,
+            value: value.map(|v| v.to_string()),
+            children: Vec::new(),
+        }
+    }
+
+    fn format_base(&self, encoding: u8, bytes: &[u8]) -> String {
+        let size = bytes.len() as u64;
+        let raw = bytes_to_uint(bytes, self.index.big_endian);
+        match encoding {
+            // DW_ATE_boolean
+            0x02 => (raw != 0).to_string(),
+            // DW_ATE_float
+            0x04 if size == 4 => f32::from_bits(raw as u32).to_string(),
+            0x04 if size == 8 => f64::from_bits(raw).to_string(),
+            // DW_ATE_signed, DW_ATE_signed_char
+            0x05 | 0x06 => {
+                let value = sign_extend(raw, size);
+                if encoding == 0x06 && size == 1 {
+                    format!("{} {}", value, format_char(raw as u8))
+                } else {
+                    value.to_string()
+                }
+            }
+            // DW_ATE_unsigned_char
+            0x08 if size == 1 => format!("{} {}", raw, format_char(raw as u8)),
+            _ if size <= 8 => format!("{} (0x{:x})", raw, raw),
+            _ => format_bytes(bytes),
+        }
+    }
+
+    fn is_char(&self, type_ref: Option<u64>) -> bool {
+        matches!(
+            self.index.resolve(type_ref).map(|(_, entry)| &entry.kind),
+            Some(TypeKind::Base { encoding: 0x06 | 0x08, size: 1 })
+        )
+    }
+
+    fn read_uint(&self, addr: u64, size: u64) -> Option<u64> {
+        if size == 0 || size > 8 {
+            return None;
+        }
+        self.dump.read(addr, size).map(|bytes| bytes_to_uint(bytes, self.index.big_endian))
+    }
+
+    fn describe_address(&self, target: u64) -> String {
+        if target == 0 {
+            return "NULL".to_string();
+        }
+        let idx = self.targets.partition_point(|(start, _, _)| *start <= target);
+        match idx.checked_sub(1).map(|i| &self.targets[i]) {
+            Some((start, size, name)) if target < start + size => {
+                if target == *start {
+                    format!("0x{:08x} <{}>", target, name)
+                } else {
+                    format!("0x{:08x} <{}+0x{:x}>", target, name, target - start)
+                }
+            }
+            _ => format!("0x{:08x}", target),
+        }
+    }
+}
+
+fn bytes_to_uint(bytes: &[u8], big_endian: bool) -> u64 {
+    let fold = |acc: u64, byte: &u8| (acc << 8) | *byte as u64;
+    if big_endian {
+        bytes.iter().take(8).fold(0, fold)
+    } else {
+        bytes.iter().take(8).rev().fold(0, fold)
+    }
+}
+
+fn sign_extend(raw: u64, size: u64) -> i64 {
+    if size == 0 || size >= 8 {
+        return raw as i64;
+    }
+    let shift = 64 - size * 8;
+    ((raw << shift) as i64) >> shift
+}
+
+fn format_char(byte: u8) -> String {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        format!("'{}'", byte as char)
+    } else {
+        format!("'\\x{:02x}'", byte)
+    }
+}
+
+fn format_c_string(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    format!("{:?}", String::from_utf8_lossy(&bytes[..end]))
+}
+
+fn format_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(" ")
+}
+
+/// Task stacks are static buffers whose name mentions "stack" (FreeRTOS static tasks,
+/// Zephyr/ThreadX stack areas), plus any symbols the caller names explicitly.
+fn find_task_stacks(symbols: &[SymbolInfo], explicit: &[String]) -> Vec<(String, u64, u64)> {
+    symbols
+        .iter()
+        .filter(|symbol| {
+            if explicit.iter().any(|name| name == &symbol.name) {
+                return true;
+            }
+            matches!(symbol.kind.as_str(), "B" | "b" | "D" | "d")
+                && symbol.size >= MIN_TASK_STACK
+                && symbol.name.to_ascii_lowercase().contains("stack")
+                && !is_linker_symbol_name(&symbol.name)
+        })
+        .filter_map(|symbol| {
+            let start = parse_hex_str(symbol.addr.as_deref()?)?;
+            Some((symbol.name.clone(), start, start.saturating_add(symbol.size)))
+        })
+        .collect()
+}
+
+/// Stacks grow down, so untouched fill bytes are counted upwards from the lowest address.
+fn measure_stack(dump: &RamDump, pattern: &[u8], name: String, start: u64, end: u64) -> StackUsage {
+    let size = end - start;
+    let mut usage = StackUsage {
+        name,
+        start: format!("0x{:08x}", start),
+        end: format!("0x{:08x}", end),
+        size,
+        used: None,
+        unused: None,
+        usage_percent: None,
+        in_dump: false,
+    };
+    let bytes = match dump.read(start, size) {
+        Some(bytes) => bytes,
+        None => return usage,
+    };
+    let width = pattern.len() as u64;
+    let unused = bytes
+        .iter()
+        .enumerate()
+        .take_while(|(idx, byte)| **byte == pattern[((start + *idx as u64) % width) as usize])
+        .count() as u64;
+    usage.in_dump = true;
+    usage.unused = Some(unused);
+    usage.used = Some(size - unused);
+    usage.usage_percent = Some((size - unused) as f64 * 100.0 / size as f64);
+    usage
+}
+
+fn compute_stack_findings(stacks: &[StackUsage], warn_percent: f64) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let overflowed: Vec<&StackUsage> = stacks.iter().filter(|s| s.unused == Some(0)).collect();
+    if !overflowed.is_empty() {
+        findings.push(Finding {
+            id: "STACK_EXHAUSTED".to_string(),
+            severity: "warn".to_string(),
+            value: overflowed.len() as u64,
+            items: overflowed.iter().map(|s| s.name.clone()).collect(),
+        });
+    }
+    let mut high: Vec<&StackUsage> = stacks
+        .iter()
+        .filter(|s| s.unused != Some(0) && s.usage_percent.map(|p| p >= warn_percent).unwrap_or(false))
+        .collect();
+    high.sort_by_key(|s| s.unused);
+    if !high.is_empty() {
+        findings.push(Finding {
+            id: "STACK_HIGH_WATER".to_string(),
+            severity: "warn".to_string(),
+            value: high.len() as u64,
+            items: high
+                .iter()
+                .map(|s| format!("{} {:.1}% ({} B free)", s.name, s.usage_percent.unwrap_or(0.0), s.unused.unwrap_or(0)))
+                .collect(),
+        });
+    }
+    let missing: Vec<&StackUsage> = stacks.iter().filter(|s| !s.in_dump).collect();
+    if !missing.is_empty() {
+        findings.push(Finding {
+            id: "STACK_NOT_IN_DUMP".to_string(),
+            severity: "info".to_string(),
+            value: missing.len() as u64,
+            items: missing.iter().map(|s| s.name.clone()).collect(),
+        });
+    }
+    findings
+}
+
+/// Patterns are written as the word value (`0xDEADBEEF`, `CC`) and laid out little-endian.
+fn parse_fill_pattern(value: &str) -> Result<Vec<u8>, String> {
+    let digits = value.trim().trim_start_matches("0x").trim_start_matches("0X");
+    let width = match digits.len() {
+        2 => 1,
+        4 => 2,
+        8 => 4,
+        _ => return Err("Fill pattern must be 1, 2 or 4 bytes of hex (e.g. 0xA5A5A5A5).".to_string()),
+    };
+    let word = u32::from_str_radix(digits, 16).map_err(|_| format!("Invalid fill pattern: {}", value))?;
+    Ok(word.to_le_bytes()[..width].to_vec())
+}
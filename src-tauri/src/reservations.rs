@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::analyzer::Finding;
+
+pub const DEFAULT_STACK_MARGIN: u64 = 1024;
+
+const STACK_TOP: &[&str] = &["_estack", "__StackTop", "__stack_top", "_stack_top", "__stack", "_stack"];
+const STACK_LIMIT: &[&str] = &["__StackLimit", "__stack_limit", "_stack_limit", "_sstack", "__stack_start__"];
+const STACK_SIZE: &[&str] = &["_Min_Stack_Size", "__STACK_SIZE", "__stack_size", "_stack_size"];
+const HEAP_BASE: &[&str] = &["__HeapBase", "__heap_base", "__heap_start", "__heap_start__", "_heap_start", "_sheap"];
+const HEAP_LIMIT: &[&str] = &["__HeapLimit", "__heap_limit", "__heap_end", "__heap_end__", "_heap_end", "_eheap"];
+const HEAP_SIZE: &[&str] = &["_Min_Heap_Size", "__HEAP_SIZE", "__heap_size", "_heap_size"];
+const STATIC_END: &[&str] = &["_ebss", "__bss_end__", "_end", "end", "__end__"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StackHeapReport {
+    pub stack_top: Option<String>,
+    pub stack_bottom: Option<String>,
+    pub stack_size: Option<u64>,
+    pub heap_start: Option<String>,
+    pub heap_end: Option<String>,
+    pub heap_size: Option<u64>,
+    pub static_end: Option<String>,
+    pub free_bytes: Option<u64>,
+    pub margin_bytes: u64,
+    pub symbols: Vec<ReservationSymbol>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationSymbol {
+    pub name: String,
+    pub value: String,
+}
+
+/// Reads the stack/heap reservation symbols emitted by common linker scripts (CubeMX,
+/// CMSIS, newlib) from full `nm -S` output. The free gap is what remains between the
+/// end of static data plus heap and the bottom of the reserved stack. The margin is a
+/// per-call setting and is filled in by `apply_stack_margin`.
+pub fn build_stack_heap_report(nm_output: &str) -> StackHeapReport {
+    let mut values: HashMap<&str, u64> = HashMap::new();
+    for line in nm_output.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let (addr, name) = match parts.len() {
+            3 => (parts[0], parts[2]),
+            4 => (parts[0], parts[3]),
+            _ => continue,
+        };
+        if let Ok(value) = u64::from_str_radix(addr, 16) {
+            values.entry(name).or_insert(value);
+        }
+    }
+
+    let mut symbols = Vec::new();
+    let mut pick = |names: &[&str]| -> Option<u64> {
+        let (name, value) = names.iter().find_map(|name| values.get(name).map(|v| (*name, *v)))?;
+        symbols.push(ReservationSymbol {
+            name: name.to_string(),
+            value: format_addr(value),
+        });
+        Some(value)
+    };
+
+    let stack_top = pick(STACK_TOP);
+    let stack_limit = pick(STACK_LIMIT);
+    let stack_size_symbol = pick(STACK_SIZE);
+    let heap_base = pick(HEAP_BASE);
+    let heap_limit = pick(HEAP_LIMIT);
+    let heap_size_symbol = pick(HEAP_SIZE);
+    let static_end = STATIC_END
+        .iter()
+        .filter_map(|name| values.get(name).map(|v| (*name, *v)))
+        .max_by_key(|(_, value)| *value);
+    if let Some((name, value)) = static_end {
+        symbols.push(ReservationSymbol {
+            name: name.to_string(),
+            value: format_addr(value),
+        });
+    }
+    let static_end = static_end.map(|(_, value)| value);
+
+    let stack_size = stack_size_symbol.or_else(|| match (stack_top, stack_limit) {
+        (Some(top), Some(limit)) if top > limit => Some(top - limit),
+        _ => None,
+    });
+    let stack_bottom = stack_limit.or_else(|| match (stack_top, stack_size) {
+        (Some(top), Some(size)) => top.checked_sub(size),
+        _ => None,
+    });
+    let heap_size = heap_size_symbol.or_else(|| match (heap_base, heap_limit) {
+        (Some(base), Some(limit)) if limit > base => Some(limit - base),
+        _ => None,
+    });
+    let heap_start = heap_base.or(static_end);
+    let heap_end = heap_limit.or_else(|| Some(heap_start?.saturating_add(heap_size?)));
+
+    let free_bytes = match (stack_bottom, heap_end.or(static_end)) {
+        (Some(bottom), Some(used_end)) => Some(bottom.saturating_sub(used_end)),
+        _ => None,
+    };
+
+    StackHeapReport {
+        stack_top: stack_top.map(format_addr),
+        stack_bottom: stack_bottom.map(format_addr),
+        stack_size,
+        heap_start: heap_start.map(format_addr),
+        heap_end: heap_end.map(format_addr),
+        heap_size,
+        static_end: static_end.map(format_addr),
+        free_bytes,
+        margin_bytes: DEFAULT_STACK_MARGIN,
+        symbols,
+    }
+}
+
+pub fn compute_stack_heap_findings(report: &StackHeapReport) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if let Some(free) = report.free_bytes {
+        if free < report.margin_bytes {
+            findings.push(Finding {
+                id: "STACK_HEAP_MARGIN".to_string(),
+                severity: "warn".to_string(),
+                value: free,
+                items: vec![
+                    format!("stack {} B", report.stack_size.unwrap_or(0)),
+                    format!("heap {} B", report.heap_size.unwrap_or(0)),
+                    format!("margin {} B", report.margin_bytes),
+                ],
+            });
+        }
+    }
+    findings
+}
+
+fn format_addr(value: u64) -> String {
+    format!("0x{:08x}", value)
+}
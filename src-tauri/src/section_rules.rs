@@ -0,0 +1,89 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::symbol_query::glob_regex;
+
+/// Assigns sections whose name matches `pattern` (a glob such as `.ramfunc*`) to `class`
+/// (text, rodata, data or bss) and says what they occupy: `flash`, `ram` or `both`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectionRule {
+    pub pattern: String,
+    pub class: String,
+    pub counts_against: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SectionClass {
+    Text,
+    Rodata,
+    Data,
+    Bss,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Classification {
+    pub class: SectionClass,
+    pub flash: bool,
+    pub ram: bool,
+}
+
+/// User rules, tried in order before the built-in `.text/.rodata/.data/.bss` prefixes.
+pub struct SectionClassifier {
+    rules: Vec<(Regex, Classification)>,
+}
+
+impl SectionClassifier {
+    pub fn new(rules: &[SectionRule]) -> Result<Self, String> {
+        let mut compiled = Vec::new();
+        for rule in rules.iter() {
+            let pattern = rule.pattern.trim();
+            if pattern.is_empty() {
+                return Err("Section rule pattern is required.".to_string());
+            }
+            let class = match rule.class.trim().to_ascii_lowercase().as_str() {
+                "text" => SectionClass::Text,
+                "rodata" => SectionClass::Rodata,
+                "data" => SectionClass::Data,
+                "bss" => SectionClass::Bss,
+                other => return Err(format!("Unknown section class {} in rule {}. Use text, rodata, data or bss.", other, pattern)),
+            };
+            let (flash, ram) = match rule.counts_against.trim().to_ascii_lowercase().as_str() {
+                "flash" => (true, false),
+                "ram" => (false, true),
+                "both" => (true, true),
+                other => return Err(format!("Section rule {} counts against {}; use flash, ram or both.", pattern, other)),
+            };
+            compiled.push((glob_regex(pattern)?, Classification { class, flash, ram }));
+        }
+        Ok(Self { rules: compiled })
+    }
+
+    pub fn classify(&self, name: &str) -> Option<Classification> {
+        if let Some((_, classification)) = self.rules.iter().find(|(pattern, _)| pattern.is_match(name)) {
+            return Some(*classification);
+        }
+        let matches = |prefix: &str| name == prefix || name.starts_with(&format!("{}.", prefix));
+        // Initialised data is stored in flash and copied to RAM at startup.
+        let (class, flash, ram) = if matches(".text") {
+            (SectionClass::Text, true, false)
+        } else if matches(".rodata") {
+            (SectionClass::Rodata, true, false)
+        } else if matches(".data") {
+            (SectionClass::Data, true, true)
+        } else if matches(".bss") {
+            (SectionClass::Bss, false, true)
+        } else {
+            return None;
+        };
+        Some(Classification { class, flash, ram })
+    }
+}
+
+/// Stable text form of the rules for the analysis cache key.
+pub fn rules_signature(rules: &[SectionRule]) -> String {
+    rules
+        .iter()
+        .map(|rule| format!("{}={}/{}", rule.pattern.trim(), rule.class.trim(), rule.counts_against.trim()))
+        .collect::<Vec<_>>()
+        .join(";")
+}
@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::analyzer::{parse_hex_str, AppState, Finding, SectionInfo};
+use crate::dwarf::{dwarf_for_session, DwarfIndex, TypeKind};
+
+const MAX_NESTING: usize = 16;
+const DEFAULT_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructLayoutReport {
+    pub structs: Vec<StructLayout>,
+    pub total_wasted: u64,
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructLayout {
+    pub name: String,
+    pub size: u64,
+    pub members: Vec<MemberLayout>,
+    pub holes: Vec<LayoutHole>,
+    pub tail_padding: u64,
+    pub wasted: u64,
+    pub instances: u64,
+    pub total_wasted: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberLayout {
+    pub name: String,
+    pub type_name: String,
+    pub offset: u64,
+    pub size: u64,
+    pub bit_offset: Option<u64>,
+    pub bit_size: Option<u64>,
+}
+
+/// Padding between `after` and the next member; `offset` is where the hole starts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutHole {
+    pub after: String,
+    pub offset: u64,
+    pub size: u64,
+}
+
+#[tauri::command]
+pub fn analyze_struct_layout(
+    state: tauri::State<'_, AppState>,
+    session_id: String,
+    limit: Option<usize>,
+) -> Result<StructLayoutReport, String> {
+    let session = state.session(&session_id)?;
+    let index = dwarf_for_session(&session)?;
+    let sections = session
+        .analysis
+        .lock()
+        .map_err(|_| "Failed to read analysis state.".to_string())?
+        .as_ref()
+        .map(|analysis| analysis.sections.clone())
+        .unwrap_or_default();
+    Ok(build_struct_layout_report(&index, &sections, limit.unwrap_or(DEFAULT_LIMIT)))
+}
+
+pub fn build_struct_layout_report(index: &DwarfIndex, sections: &[SectionInfo], limit: usize) -> StructLayoutReport {
+    let typedef_names = anonymous_typedef_names(index);
+    let instances = count_ram_instances(index, sections, &typedef_names);
+
+    // Every compilation unit carries its own copy of shared types; keep one per name and size.
+    let mut layouts: HashMap<(String, u64), StructLayout> = HashMap::new();
+    for (offset, entry) in index.types.iter() {
+        let (keyword, size, members) = match &entry.kind {
+            TypeKind::Struct { keyword, size, members } if *keyword != "union" && *size > 0 => (keyword, *size, members),
+            _ => continue,
+        };
+        let name = struct_name(keyword, entry.name.as_deref(), *offset, &typedef_names);
+        if layouts.contains_key(&(name.clone(), size)) {
+            continue;
+        }
+        let mut members: Vec<MemberLayout> = members
+            .iter()
+            .map(|member| MemberLayout {
+                name: member.name.clone().unwrap_or_else(|| "<anonymous>".to_string()),
+                type_name: index.type_name(member.type_ref),
+                offset: member.offset,
+                size: index.type_size(member.type_ref).unwrap_or(0),
+                bit_offset: member.bit_offset,
+                bit_size: member.bit_size,
+            })
+            .collect();
+        members.sort_by_key(|member| member_bits(member).0);
+        let (holes, tail_padding) = find_holes(&members, size);
+        let wasted = holes.iter().map(|hole| hole.size).sum::<u64>() + tail_padding;
+        let count = instances.get(&(name.clone(), size)).copied().unwrap_or(0);
+        layouts.insert(
+            (name.clone(), size),
+            StructLayout {
+                name,
+                size,
+                members,
+                holes,
+                tail_padding,
+                wasted,
+                instances: count,
+                total_wasted: wasted * count,
+            },
+        );
+    }
+
+    let mut structs: Vec<StructLayout> = layouts.into_values().filter(|layout| layout.wasted > 0).collect();
+    structs.sort_by(|a, b| {
+        b.total_wasted
+            .cmp(&a.total_wasted)
+            .then_with(|| b.wasted.cmp(&a.wasted))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    let total_wasted = structs.iter().map(|layout| layout.total_wasted).sum();
+    let findings = compute_struct_layout_findings(&structs, total_wasted);
+    structs.truncate(limit);
+    StructLayoutReport {
+        structs,
+        total_wasted,
+        findings,
+    }
+}
+
+fn compute_struct_layout_findings(structs: &[StructLayout], total_wasted: u64) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if total_wasted > 0 {
+        findings.push(Finding {
+            id: "STRUCT_PADDING".to_string(),
+            severity: "info".to_string(),
+            value: total_wasted,
+            items: structs
+                .iter()
+                .filter(|layout| layout.total_wasted > 0)
+                .take(5)
+                .map(|layout| {
+                    format!(
+                        "{}: {} B padding x {} = {} B",
+                        layout.name, layout.wasted, layout.instances, layout.total_wasted
+                    )
+                })
+                .collect(),
+        });
+    }
+    findings
+}
+
+/// Start and end of a member in bits from the start of the struct.
+fn member_bits(member: &MemberLayout) -> (u64, u64) {
+    match (member.bit_size, member.bit_offset) {
+        (Some(bits), Some(offset)) => (offset, offset + bits),
+        _ => (member.offset * 8, (member.offset + member.size) * 8),
+    }
+}
+
+/// Byte-sized holes between members plus tail padding. Holes inside bitfield storage
+/// units smaller than a byte are ignored.
+fn find_holes(members: &[MemberLayout], size: u64) -> (Vec<LayoutHole>, u64) {
+    let mut holes = Vec::new();
+    let mut cursor = 0u64;
+    let mut previous = "<start>".to_string();
+    for member in members {
+        let (start, end) = member_bits(member);
+        if start >= cursor + 8 {
+            holes.push(LayoutHole {
+                after: previous.clone(),
+                offset: cursor.div_ceil(8),
+                size: start / 8 - cursor.div_ceil(8),
+            });
+        }
+        if end > cursor {
+            cursor = end;
+            previous = member.name.clone();
+        }
+    }
+    holes.retain(|hole| hole.size > 0);
+    let tail_padding = size.saturating_sub(cursor.div_ceil(8));
+    (holes, tail_padding)
+}
+
+/// Counts struct instances in `.data`/`.bss`, including arrays of structs and structs
+/// nested inside other statically allocated structs.
+fn count_ram_instances(
+    index: &DwarfIndex,
+    sections: &[SectionInfo],
+    typedef_names: &HashMap<u64, String>,
+) -> HashMap<(String, u64), u64> {
+    let ranges: Vec<(u64, u64)> = sections
+        .iter()
+        .filter(|section| {
+            let name = section.name.as_str();
+            name == ".data" || name.starts_with(".data.") || name == ".bss" || name.starts_with(".bss.")
+        })
+        .filter_map(|section| {
+            let start = parse_hex_str(section.vma.as_deref()?)?;
+            Some((start, start.saturating_add(section.size)))
+        })
+        .collect();
+    let mut counts = HashMap::new();
+    for variable in index.variables.iter() {
+        if ranges.iter().any(|(start, end)| variable.addr >= *start && variable.addr < *end) {
+            count_type(index, variable.type_ref, 1, 0, typedef_names, &mut counts);
+        }
+    }
+    counts
+}
+
+fn count_type(
+    index: &DwarfIndex,
+    type_ref: Option<u64>,
+    multiplier: u64,
+    depth: usize,
+    typedef_names: &HashMap<u64, String>,
+    counts: &mut HashMap<(String, u64), u64>,
+) {
+    if depth > MAX_NESTING || multiplier == 0 {
+        return;
+    }
+    let (offset, entry) = match index.resolve(type_ref) {
+        Some(resolved) => resolved,
+        None => return,
+    };
+    match &entry.kind {
+        TypeKind::Struct { keyword, size, members } => {
+            let name = struct_name(keyword, entry.name.as_deref(), offset, typedef_names);
+            *counts.entry((name, *size)).or_insert(0) += multiplier;
+            for member in members {
+                count_type(index, member.type_ref, multiplier, depth + 1, typedef_names, counts);
+            }
+        }
+        TypeKind::Array { element, dims } => {
+            let elements = dims.iter().map(|dim| dim.unwrap_or(0)).product::<u64>();
+            count_type(index, *element, multiplier.saturating_mul(elements), depth + 1, typedef_names, counts);
+        }
+        _ => {}
+    }
+}
+
+/// `typedef struct { ... } foo_t;` leaves the struct itself unnamed.
+fn anonymous_typedef_names(index: &DwarfIndex) -> HashMap<u64, String> {
+    let mut names = HashMap::new();
+    for entry in index.types.values() {
+        if let (TypeKind::Typedef { target: Some(target) }, Some(name)) = (&entry.kind, entry.name.as_ref()) {
+            if index.types.get(target).map(|t| t.name.is_none()).unwrap_or(false) {
+                names.entry(*target).or_insert_with(|| name.clone());
+            }
+        }
+    }
+    names
+}
+
+fn struct_name(keyword: &str, name: Option<&str>, offset: u64, typedef_names: &HashMap<u64, String>) -> String {
+    match (name, typedef_names.get(&offset)) {
+        (Some(name), _) => format!("{} {}", keyword, name),
+        (None, Some(typedef)) => typedef.clone(),
+        (None, None) => format!("{} <anonymous@0x{:x}>", keyword, offset),
+    }
+}
@@ -0,0 +1,268 @@
+use roxmltree::{Document, Node};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+const DEFAULT_REGISTER_BITS: u64 = 32;
+
+/// Peripheral/register/field tree from a CMSIS-SVD file, with registers and clusters
+/// flattened to absolute offsets and `dim` arrays expanded.
+pub struct SvdDevice {
+    pub peripherals: Vec<SvdPeripheral>,
+}
+
+pub struct SvdPeripheral {
+    pub name: String,
+    pub base: u64,
+    pub size: u64,
+    pub registers: Vec<SvdRegister>,
+}
+
+pub struct SvdRegister {
+    pub name: String,
+    pub offset: u64,
+    pub size: u64,
+    pub fields: Vec<SvdField>,
+}
+
+#[derive(Clone)]
+pub struct SvdField {
+    pub name: String,
+    pub lsb: u64,
+    pub width: u64,
+}
+
+/// `path` is written the way CMSIS headers spell it, e.g. `USART2->ISR.RXNE`; `fields`
+/// lists every field stored in the addressed byte.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeripheralMatch {
+    pub peripheral: String,
+    pub register: Option<String>,
+    pub offset: u64,
+    pub path: String,
+    pub fields: Vec<String>,
+}
+
+pub fn parse_svd_file(path: &str) -> Result<SvdDevice, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("Failed to read SVD file {}: {}", path, e))?;
+    parse_svd(&contents).map_err(|e| format!("Failed to parse SVD file {}: {}", path, e))
+}
+
+pub fn parse_svd(contents: &str) -> Result<SvdDevice, String> {
+    let doc = Document::parse(contents).map_err(|e| e.to_string())?;
+    let device = doc.root_element();
+    if device.tag_name().name() != "device" {
+        return Err("root element is not <device>".to_string());
+    }
+    let default_bits = child_number(device, "size").unwrap_or(DEFAULT_REGISTER_BITS);
+
+    let nodes: Vec<Node> = child(device, "peripherals")
+        .map(|p| p.children().filter(|n| n.has_tag_name("peripheral")).collect())
+        .unwrap_or_default();
+    let by_name: HashMap<String, Node> = nodes
+        .iter()
+        .filter_map(|node| Some((child_text(*node, "name")?.to_string(), *node)))
+        .collect();
+
+    let mut peripherals = Vec::new();
+    for node in nodes.iter() {
+        let name = match child_text(*node, "name") {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let base = child_number(*node, "baseAddress").unwrap_or(0);
+        let bits = child_number(*node, "size").unwrap_or(default_bits);
+        // Derived peripherals reuse the register block of their parent unless they define one.
+        let register_source = match (child(*node, "registers"), node.attribute("derivedFrom")) {
+            (Some(registers), _) => Some(registers),
+            (None, Some(parent)) => by_name.get(parent).and_then(|parent| child(*parent, "registers")),
+            (None, None) => None,
+        };
+        let mut registers = Vec::new();
+        if let Some(source) = register_source {
+            collect_registers(source, 0, "", bits, &mut registers);
+        }
+        registers.sort_by_key(|register| register.offset);
+        let block_source = match (child(*node, "addressBlock"), node.attribute("derivedFrom")) {
+            (None, Some(parent)) => by_name.get(parent).copied().unwrap_or(*node),
+            _ => *node,
+        };
+        let size = block_source
+            .children()
+            .filter(|n| n.has_tag_name("addressBlock"))
+            .filter_map(|block| Some(child_number(block, "offset").unwrap_or(0) + child_number(block, "size")?))
+            .chain(registers.iter().map(|register| register.offset + register.size))
+            .max()
+            .unwrap_or(1);
+        peripherals.push(SvdPeripheral {
+            name,
+            base,
+            size,
+            registers,
+        });
+    }
+    peripherals.sort_by_key(|peripheral| peripheral.base);
+
+    Ok(SvdDevice { peripherals })
+}
+
+impl SvdDevice {
+    pub fn lookup(&self, addr: u64) -> Option<PeripheralMatch> {
+        let peripheral = self
+            .peripherals
+            .iter()
+            .filter(|p| addr >= p.base && addr - p.base < p.size)
+            .max_by_key(|p| p.base)?;
+        let offset = addr - peripheral.base;
+        let register = peripheral
+            .registers
+            .iter()
+            .find(|r| offset >= r.offset && offset < r.offset + r.size);
+        let register = match register {
+            Some(register) => register,
+            None => {
+                return Some(PeripheralMatch {
+                    peripheral: peripheral.name.clone(),
+                    register: None,
+                    offset,
+                    path: format!("{}+0x{:x}", peripheral.name, offset),
+                    fields: Vec::new(),
+                })
+            }
+        };
+
+        // Registers are little-endian on Cortex-M, so byte N holds bits 8N..8N+7.
+        let byte = offset - register.offset;
+        let (low, high) = (byte * 8, byte * 8 + 8);
+        let fields: Vec<String> = register
+            .fields
+            .iter()
+            .filter(|f| f.lsb < high && f.lsb + f.width > low)
+            .map(|f| f.name.clone())
+            .collect();
+        let path = match (fields.len(), byte) {
+            (1, _) => format!("{}->{}.{}", peripheral.name, register.name, fields[0]),
+            (_, 0) => format!("{}->{}", peripheral.name, register.name),
+            _ => format!("{}->{}+{}", peripheral.name, register.name, byte),
+        };
+        Some(PeripheralMatch {
+            peripheral: peripheral.name.clone(),
+            register: Some(register.name.clone()),
+            offset,
+            path,
+            fields,
+        })
+    }
+}
+
+fn collect_registers(parent: Node, base: u64, prefix: &str, bits: u64, out: &mut Vec<SvdRegister>) {
+    for node in parent.children().filter(|n| n.is_element()) {
+        let is_cluster = node.has_tag_name("cluster");
+        if !is_cluster && !node.has_tag_name("register") {
+            continue;
+        }
+        let name = match child_text(node, "name") {
+            Some(name) => name,
+            None => continue,
+        };
+        let offset = base + child_number(node, "addressOffset").unwrap_or(0);
+        let bits = child_number(node, "size").unwrap_or(bits);
+        for (name, offset) in expand_dim(node, name, offset) {
+            let name = format!("{}{}", prefix, name);
+            if is_cluster {
+                collect_registers(node, offset, &format!("{}.", name), bits, out);
+            } else {
+                out.push(SvdRegister {
+                    name,
+                    offset,
+                    size: (bits / 8).max(1),
+                    fields: collect_fields(node),
+                });
+            }
+        }
+    }
+}
+
+/// Expands `dim`/`dimIncrement` arrays: `CCR%s` with `dimIndex` or `DR[%s]` with 0..dim.
+fn expand_dim(node: Node, name: &str, offset: u64) -> Vec<(String, u64)> {
+    let dim = match child_number(node, "dim") {
+        Some(dim) if dim > 0 && name.contains("%s") => dim,
+        _ => return vec![(name.to_string(), offset)],
+    };
+    let increment = child_number(node, "dimIncrement").unwrap_or(0);
+    let indices = child_text(node, "dimIndex").map(expand_dim_index).unwrap_or_default();
+    (0..dim)
+        .map(|idx| {
+            let label = indices.get(idx as usize).cloned().unwrap_or_else(|| idx.to_string());
+            (name.replace("%s", &label), offset + idx * increment)
+        })
+        .collect()
+}
+
+fn expand_dim_index(value: &str) -> Vec<String> {
+    let value = value.trim();
+    if let Some((start, end)) = value.split_once('-') {
+        if let (Ok(start), Ok(end)) = (start.trim().parse::<u64>(), end.trim().parse::<u64>()) {
+            return (start..=end).map(|idx| idx.to_string()).collect();
+        }
+        let (start, end) = (start.trim().as_bytes(), end.trim().as_bytes());
+        if start.len() == 1 && end.len() == 1 {
+            return (start[0]..=end[0]).map(|c| (c as char).to_string()).collect();
+        }
+    }
+    value.split(',').map(|part| part.trim().to_string()).collect()
+}
+
+fn collect_fields(register: Node) -> Vec<SvdField> {
+    let fields = match child(register, "fields") {
+        Some(fields) => fields,
+        None => return Vec::new(),
+    };
+    fields
+        .children()
+        .filter(|n| n.has_tag_name("field"))
+        .flat_map(|field| {
+            let name = child_text(field, "name").unwrap_or("").to_string();
+            let (lsb, width) = field_bits(field).unwrap_or((0, 1));
+            expand_dim(field, &name, lsb)
+                .into_iter()
+                .map(move |(name, lsb)| SvdField { name, lsb, width })
+        })
+        .collect()
+}
+
+/// Fields give their position as bitOffset/bitWidth, lsb/msb or bitRange `[msb:lsb]`.
+fn field_bits(field: Node) -> Option<(u64, u64)> {
+    if let Some(offset) = child_number(field, "bitOffset") {
+        return Some((offset, child_number(field, "bitWidth").unwrap_or(1)));
+    }
+    if let (Some(lsb), Some(msb)) = (child_number(field, "lsb"), child_number(field, "msb")) {
+        return Some((lsb, msb.checked_sub(lsb)? + 1));
+    }
+    let range = child_text(field, "bitRange")?.trim().trim_start_matches('[').trim_end_matches(']');
+    let (msb, lsb) = range.split_once(':')?;
+    let (msb, lsb) = (msb.trim().parse::<u64>().ok()?, lsb.trim().parse::<u64>().ok()?);
+    Some((lsb, msb.checked_sub(lsb)? + 1))
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name(name))
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name).and_then(|n| n.text()).map(|text| text.trim())
+}
+
+fn child_number(node: Node, name: &str) -> Option<u64> {
+    let text = child_text(node, name)?;
+    if text.is_empty() {
+        return None;
+    }
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok()
+    } else if let Some(binary) = text.strip_prefix('#') {
+        u64::from_str_radix(binary, 2).ok()
+    } else {
+        text.parse::<u64>().ok()
+    }
+}
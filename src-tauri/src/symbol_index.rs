@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::analyzer::{parse_hex_str, FacetItem, SymbolFacets, SymbolInfo};
+
+/// The symbol table of a session plus the lookup structures built once after analysis:
+/// address intervals for containment lookups, a trigram index over lowercase names,
+/// precomputed sort orders and facet counts. Derefs to the plain symbol slice.
+#[derive(Default)]
+pub struct SymbolIndex {
+    symbols: Vec<SymbolInfo>,
+    lower_names: Vec<String>,
+    /// Sized symbols as (start, end, index), sorted by start.
+    intervals: Vec<(u64, u64, u32)>,
+    /// Running maximum of `end` over `intervals`, so a backward walk can stop early.
+    max_end: Vec<u64>,
+    trigrams: HashMap<[u8; 3], Vec<u32>>,
+    by_size: Vec<u32>,
+    by_name: Vec<u32>,
+    by_kind: Vec<u32>,
+    by_section: Vec<u32>,
+    size_rank: Vec<u32>,
+    name_rank: Vec<u32>,
+    kind_rank: Vec<u32>,
+    section_rank: Vec<u32>,
+    facets: SymbolFacets,
+}
+
+impl Deref for SymbolIndex {
+    type Target = [SymbolInfo];
+
+    fn deref(&self) -> &[SymbolInfo] {
+        &self.symbols
+    }
+}
+
+impl SymbolIndex {
+    pub fn new(symbols: Vec<SymbolInfo>) -> Self {
+        let lower_names: Vec<String> = symbols.iter().map(|symbol| symbol.name.to_ascii_lowercase()).collect();
+
+        let mut intervals: Vec<(u64, u64, u32)> = symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol)| symbol.size > 0)
+            .filter_map(|(idx, symbol)| {
+                let start = parse_hex_str(symbol.addr.as_deref()?)?;
+                Some((start, start.saturating_add(symbol.size), idx as u32))
+            })
+            .collect();
+        intervals.sort_by_key(|(start, _, idx)| (*start, *idx));
+        let max_end = intervals
+            .iter()
+            .scan(0u64, |max, (_, end, _)| {
+                *max = (*max).max(*end);
+                Some(*max)
+            })
+            .collect();
+
+        let mut trigrams: HashMap<[u8; 3], Vec<u32>> = HashMap::new();
+        for (idx, name) in lower_names.iter().enumerate() {
+            for gram in name.as_bytes().windows(3) {
+                let list = trigrams.entry([gram[0], gram[1], gram[2]]).or_default();
+                // Names are visited in order, so a repeated trigram only needs a tail check.
+                if list.last() != Some(&(idx as u32)) {
+                    list.push(idx as u32);
+                }
+            }
+        }
+
+        let mut by_size: Vec<u32> = (0..symbols.len() as u32).collect();
+        by_size.sort_by_key(|idx| symbols[*idx as usize].size);
+        let mut by_name: Vec<u32> = (0..symbols.len() as u32).collect();
+        by_name.sort_by(|a, b| symbols[*a as usize].name.cmp(&symbols[*b as usize].name));
+        // Kind and section ties keep name order, so the walk below starts from `by_name`.
+        let mut by_kind = by_name.clone();
+        by_kind.sort_by(|a, b| symbols[*a as usize].kind.cmp(&symbols[*b as usize].kind));
+        let mut by_section = by_name.clone();
+        by_section.sort_by(|a, b| section_label(&symbols[*a as usize]).cmp(section_label(&symbols[*b as usize])));
+        let ranks = |order: &[u32]| {
+            let mut rank = vec![0u32; order.len()];
+            for (pos, idx) in order.iter().enumerate() {
+                rank[*idx as usize] = pos as u32;
+            }
+            rank
+        };
+        let size_rank = ranks(&by_size);
+        let name_rank = ranks(&by_name);
+        let kind_rank = ranks(&by_kind);
+        let section_rank = ranks(&by_section);
+        let facets = build_facets(&symbols);
+
+        Self {
+            symbols,
+            lower_names,
+            intervals,
+            max_end,
+            trigrams,
+            by_size,
+            by_name,
+            by_kind,
+            by_section,
+            size_rank,
+            name_rank,
+            kind_rank,
+            section_rank,
+            facets,
+        }
+    }
+
+    pub fn facets(&self) -> &SymbolFacets {
+        &self.facets
+    }
+
+    /// The innermost sized symbol covering `addr` (the one starting closest below it),
+    /// with its start address. Ties go to the symbol listed first.
+    pub fn containing(&self, addr: u64) -> Option<(u64, &SymbolInfo)> {
+        let upper = self.intervals.partition_point(|(start, _, _)| *start <= addr);
+        let mut found: Option<(u64, u32)> = None;
+        for pos in (0..upper).rev() {
+            if self.max_end[pos] <= addr {
+                break;
+            }
+            let (start, end, idx) = self.intervals[pos];
+            if let Some((best_start, _)) = found {
+                if start < best_start {
+                    break;
+                }
+            }
+            if addr < end {
+                found = Some((start, idx));
+            }
+        }
+        found.map(|(start, idx)| (start, &self.symbols[idx as usize]))
+    }
+
+    /// Indices of symbols whose name contains `needle` (case-insensitive), in table order.
+    /// Needles shorter than a trigram fall back to a scan of the lowercase names.
+    pub fn name_matches(&self, needle: &str) -> Vec<u32> {
+        let needle = needle.to_ascii_lowercase();
+        if needle.len() < 3 {
+            return self.scan(|idx| self.lower_names[idx].contains(&needle));
+        }
+        let mut lists: Vec<&Vec<u32>> = Vec::new();
+        for gram in needle.as_bytes().windows(3) {
+            match self.trigrams.get(&[gram[0], gram[1], gram[2]]) {
+                Some(list) => lists.push(list),
+                None => return Vec::new(),
+            }
+        }
+        lists.sort_by_key(|list| list.len());
+        let mut candidates: Vec<u32> = lists[0].clone();
+        for list in lists.iter().skip(1) {
+            // Posting lists are sorted, so a merge walk intersects them.
+            let mut other = list.iter().peekable();
+            candidates.retain(|idx| {
+                while other.next_if(|next| *next < idx).is_some() {}
+                other.peek() == Some(&idx)
+            });
+            if candidates.is_empty() {
+                break;
+            }
+        }
+        // Trigrams do not encode order, so longer needles still need a substring check.
+        if needle.len() > 3 {
+            candidates.retain(|idx| self.lower_names[*idx as usize].contains(&needle));
+        }
+        candidates
+    }
+
+    pub fn scan(&self, keep: impl Fn(usize) -> bool) -> Vec<u32> {
+        (0..self.symbols.len() as u32).filter(|idx| keep(*idx as usize)).collect()
+    }
+
+    /// Orders `indices` by `sort` (`name`, `kind`, `section` or size), reversing for descending order the
+    /// same way a stable sort followed by `reverse` would.
+    pub fn sorted(&self, mut indices: Vec<u32>, sort: Option<&str>, descending: bool) -> Vec<u32> {
+        let (order, rank) = match sort {
+            Some("name") => (&self.by_name, &self.name_rank),
+            Some("kind") => (&self.by_kind, &self.kind_rank),
+            Some("section") | Some("section_guess") => (&self.by_section, &self.section_rank),
+            _ => (&self.by_size, &self.size_rank),
+        };
+        if indices.len() * 8 >= self.symbols.len() {
+            // Dense selections: walk the precomputed order instead of sorting.
+            let mut selected = vec![false; self.symbols.len()];
+            for idx in indices.iter() {
+                selected[*idx as usize] = true;
+            }
+            indices = order.iter().copied().filter(|idx| selected[*idx as usize]).collect();
+        } else {
+            indices.sort_by_key(|idx| rank[*idx as usize]);
+        }
+        if descending {
+            indices.reverse();
+        }
+        indices
+    }
+}
+
+/// The section shown for a symbol: its header section, else the guessed class.
+fn section_label(symbol: &SymbolInfo) -> &str {
+    symbol.section.as_deref().unwrap_or(&symbol.section_guess)
+}
+
+fn build_facets(symbols: &[SymbolInfo]) -> SymbolFacets {
+    let mut section_counts: HashMap<String, (usize, u64)> = HashMap::new();
+    let mut class_counts: HashMap<String, (usize, u64)> = HashMap::new();
+    let mut kind_counts: HashMap<String, (usize, u64)> = HashMap::new();
+    let add = |counts: &mut HashMap<String, (usize, u64)>, value: &str, size: u64| {
+        if !value.trim().is_empty() {
+            let entry = counts.entry(value.to_string()).or_insert((0, 0));
+            entry.0 += 1;
+            entry.1 += size;
+        }
+    };
+    for symbol in symbols.iter() {
+        if let Some(section) = symbol.section.as_deref() {
+            add(&mut section_counts, section, symbol.size);
+        }
+        add(&mut class_counts, &symbol.section_guess, symbol.size);
+        add(&mut kind_counts, &symbol.kind, symbol.size);
+    }
+    let into_items = |counts: HashMap<String, (usize, u64)>| {
+        let mut items: Vec<FacetItem> = counts
+            .into_iter()
+            .map(|(value, (count, size))| FacetItem { value, count, size })
+            .collect();
+        items.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+        items
+    };
+    SymbolFacets {
+        sections: into_items(section_counts),
+        classes: into_items(class_counts),
+        kinds: into_items(kind_counts),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, addr: u64, size: u64) -> SymbolInfo {
+        SymbolInfo {
+            name: name.to_string(),
+            size,
+            addr: Some(format!("{:08x}", addr)),
+            kind: "T".to_string(),
+            section_guess: "text".to_string(),
+            ..SymbolInfo::default()
+        }
+    }
+
+    fn names(index: &SymbolIndex, indices: &[u32]) -> Vec<String> {
+        indices.iter().map(|idx| index[*idx as usize].name.clone()).collect()
+    }
+
+    #[test]
+    fn containing_picks_the_innermost_symbol() {
+        let index = SymbolIndex::new(vec![
+            symbol("image", 0x0, 0x1000),
+            symbol("outer", 0x100, 0x100),
+            symbol("inner", 0x140, 0x10),
+            symbol("first_alias", 0x400, 0x8),
+            symbol("second_alias", 0x400, 0x8),
+            symbol("marker", 0x500, 0),
+        ]);
+        let name = |addr| index.containing(addr).map(|(_, symbol)| symbol.name.clone());
+        assert_eq!(name(0x145).as_deref(), Some("inner"));
+        assert_eq!(index.containing(0x145).map(|(start, _)| start), Some(0x140));
+        // The inner symbol ends before 0x150, so the walk goes on to the one around it.
+        assert_eq!(name(0x150).as_deref(), Some("outer"));
+        assert_eq!(name(0x300).as_deref(), Some("image"));
+        assert_eq!(name(0x404).as_deref(), Some("first_alias"));
+        assert_eq!(name(0x500).as_deref(), Some("image"));
+        assert_eq!(name(0x1000), None);
+    }
+
+    #[test]
+    fn name_matches_intersects_trigram_lists() {
+        let index = SymbolIndex::new(vec![
+            symbol("HAL_UART_Transmit", 0x100, 4),
+            symbol("uart_init", 0x104, 4),
+            symbol("trau_uart", 0x108, 4),
+            symbol("spi_init", 0x10c, 4),
+            symbol("abc_bcd", 0x110, 4),
+        ]);
+        assert_eq!(names(&index, &index.name_matches("UART")), vec!["HAL_UART_Transmit", "uart_init", "trau_uart"]);
+        assert_eq!(names(&index, &index.name_matches("uart_")), vec!["HAL_UART_Transmit", "uart_init"]);
+        // Both trigrams of `abcd` occur in `abc_bcd`, but the needle itself does not.
+        assert!(index.name_matches("abcd").is_empty());
+        assert_eq!(names(&index, &index.name_matches("_in")), vec!["uart_init", "spi_init"]);
+        assert_eq!(names(&index, &index.name_matches("sp")), vec!["spi_init"]);
+        assert!(index.name_matches("xyz").is_empty());
+    }
+
+    #[test]
+    fn sorted_gives_the_same_order_for_dense_and_sparse_selections() {
+        let symbols: Vec<SymbolInfo> = (0..40)
+            .map(|n| SymbolInfo {
+                kind: if n % 3 == 0 { "D" } else { "T" }.to_string(),
+                section: (n % 5 != 0).then(|| if n % 2 == 0 { ".bss" } else { ".data" }.to_string()),
+                ..symbol(&format!("sym{:02}", n), 0x100 + n * 4, n % 7)
+            })
+            .collect();
+        let index = SymbolIndex::new(symbols);
+        let sparse: Vec<u32> = vec![31, 3, 17];
+        let dense: Vec<u32> = (0..40).rev().collect();
+
+        assert_eq!(names(&index, &index.sorted(sparse.clone(), Some("name"), false)), vec!["sym03", "sym17", "sym31"]);
+        assert_eq!(names(&index, &index.sorted(sparse, Some("size"), true)), vec!["sym31", "sym17", "sym03"]);
+
+        for sort in [Some("name"), None, Some("kind"), Some("section")] {
+            for descending in [false, true] {
+                let all = index.sorted(dense.clone(), sort, descending);
+                let mut expected: Vec<u32> = (0..40).collect();
+                // Size ties keep table order and kind/section ties name order, the same here.
+                expected.sort_by_key(|idx| {
+                    let symbol = &index[*idx as usize];
+                    match sort {
+                        Some("name") => (String::new(), 0),
+                        Some("kind") => (symbol.kind.clone(), 0),
+                        Some("section") => (section_label(symbol).to_string(), 0),
+                        _ => (String::new(), symbol.size),
+                    }
+                });
+                if descending {
+                    expected.reverse();
+                }
+                assert_eq!(all, expected, "{:?} descending={}", sort, descending);
+                // A sparse pick comes out in the same relative order as the dense walk.
+                let picked = index.sorted(vec![38, 5, 12], sort, descending);
+                let filtered: Vec<u32> = all.iter().copied().filter(|idx| [38, 5, 12].contains(idx)).collect();
+                assert_eq!(picked, filtered, "{:?} descending={}", sort, descending);
+            }
+        }
+    }
+}
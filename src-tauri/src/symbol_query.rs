@@ -0,0 +1,442 @@
+use regex::{Regex, RegexBuilder};
+
+use crate::analyzer::{parse_hex_str, SymbolInfo};
+
+/// A parsed symbol filter. Terms separated by whitespace must all match; `|` (or `or`)
+/// separates alternatives, `!` (or `not`) negates and parentheses group:
+///
+/// `size>1k section:bss !name:/^_/`, `(object:uart* | lib:libhal.a) size:64..256`
+///
+/// `name`, `object` and `library` match substrings, `/regex/` or globs with `*` and `?`.
+/// `section` (the ELF section), `class` (text/rodata/data/bss/other) and `kind` take a
+/// comma-separated list of exact values, a glob or a regex. `size` and `addr` take `<`,
+/// `<=`, `>`, `>=`, `=` or a `min..max` range; sizes accept k/M suffixes.
+/// A term without a field matches the name, as does a C++ operator such as `operator<`.
+///
+/// All text matching ignores case, regexes included. Section values, globs and lists are
+/// compared without the leading dot; a regex sees the section name as written.
+///
+/// Queries reach the filter through the `list_symbols` command only. A command-line front
+/// end is out of scope: the binary has no entry point besides the Tauri app.
+#[derive(Debug)]
+pub enum SymbolFilter {
+    All(Vec<SymbolFilter>),
+    Any(Vec<SymbolFilter>),
+    Not(Box<SymbolFilter>),
+    Text(TextField, TextMatch),
+    Range(RangeField, u64, u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextField {
+    Name,
+    Section,
+    Class,
+    Kind,
+    Object,
+    Library,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RangeField {
+    Size,
+    Addr,
+}
+
+#[derive(Debug)]
+pub enum TextMatch {
+    /// Lowercase needle.
+    Contains(String),
+    /// Lowercase alternatives compared whole.
+    OneOf(Vec<String>),
+    /// Whole-value glob, compared against the normalized value.
+    Glob(Regex),
+    Pattern(Regex),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Or,
+    Not,
+    Term(String),
+}
+
+impl SymbolFilter {
+    pub fn parse(query: &str) -> Result<SymbolFilter, String> {
+        let tokens = tokenize(query)?;
+        let mut pos = 0;
+        let filter = parse_or(&tokens, &mut pos)?;
+        if pos < tokens.len() {
+            return Err("Unbalanced ')' in symbol query.".to_string());
+        }
+        Ok(filter)
+    }
+
+    pub fn matches(&self, symbol: &SymbolInfo) -> bool {
+        match self {
+            SymbolFilter::All(filters) => filters.iter().all(|f| f.matches(symbol)),
+            SymbolFilter::Any(filters) => filters.iter().any(|f| f.matches(symbol)),
+            SymbolFilter::Not(filter) => !filter.matches(symbol),
+            SymbolFilter::Text(field, matcher) => {
+                let value = match field {
+                    TextField::Name => Some(symbol.name.as_str()),
+                    TextField::Section => symbol.section.as_deref(),
+                    TextField::Class => Some(symbol.section_guess.as_str()),
+                    TextField::Kind => Some(symbol.kind.as_str()),
+                    TextField::Object => symbol.object.as_deref(),
+                    TextField::Library => symbol.library.as_deref(),
+                };
+                value.map(|value| matcher.matches(*field, value)).unwrap_or(false)
+            }
+            SymbolFilter::Range(field, min, max) => {
+                let value = match field {
+                    RangeField::Size => Some(symbol.size),
+                    RangeField::Addr => symbol.addr.as_deref().and_then(parse_hex_str),
+                };
+                value.map(|value| value >= *min && value <= *max).unwrap_or(false)
+            }
+        }
+    }
+
+    /// A name substring every match must contain, usable to narrow candidates through
+    /// the trigram index before the full filter runs.
+    pub fn required_name_substring(&self) -> Option<&str> {
+        match self {
+            SymbolFilter::Text(TextField::Name, TextMatch::Contains(needle)) => Some(needle),
+            SymbolFilter::All(filters) => filters
+                .iter()
+                .filter_map(|f| f.required_name_substring())
+                .max_by_key(|needle| needle.len()),
+            _ => None,
+        }
+    }
+}
+
+impl TextMatch {
+    fn matches(&self, field: TextField, value: &str) -> bool {
+        match self {
+            TextMatch::Contains(needle) => value.to_ascii_lowercase().contains(needle.as_str()),
+            TextMatch::OneOf(values) => {
+                let value = normalize(field, value);
+                values.contains(&value)
+            }
+            TextMatch::Glob(glob) => glob.is_match(&normalize(field, value)),
+            TextMatch::Pattern(pattern) => pattern.is_match(value),
+        }
+    }
+}
+
+/// Sections are compared without their leading dot so `bss` and `.bss` both match.
+fn normalize(field: TextField, value: &str) -> String {
+    let value = value.trim().to_ascii_lowercase();
+    match field {
+        TextField::Section => value.trim_start_matches('.').to_string(),
+        _ => value,
+    }
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+        match c {
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '|' => tokens.push(Token::Or),
+            '!' => tokens.push(Token::Not),
+            _ => {
+                let mut term = String::new();
+                let mut delimiter: Option<char> = None;
+                while pos < chars.len() {
+                    let c = chars[pos];
+                    match delimiter {
+                        Some(close) => {
+                            if c == '\\' && close == '/' && pos + 1 < chars.len() {
+                                term.push(c);
+                                term.push(chars[pos + 1]);
+                                pos += 2;
+                                continue;
+                            }
+                            if c == close {
+                                delimiter = None;
+                            }
+                        }
+                        None => {
+                            if c.is_whitespace() || c == '(' || c == ')' || c == '|' {
+                                break;
+                            }
+                            // Regex and quoted values may contain spaces and parentheses.
+                            if (c == '/' || c == '"') && (term.is_empty() || term.ends_with(':')) {
+                                delimiter = Some(c);
+                            }
+                        }
+                    }
+                    term.push(c);
+                    pos += 1;
+                }
+                if let Some(close) = delimiter {
+                    return Err(format!("Missing closing {} in symbol query.", close));
+                }
+                tokens.push(match term.to_ascii_lowercase().as_str() {
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    "and" => continue,
+                    _ => Token::Term(term),
+                });
+                continue;
+            }
+        }
+        pos += 1;
+    }
+    Ok(tokens)
+}
+
+fn parse_or(tokens: &[Token], pos: &mut usize) -> Result<SymbolFilter, String> {
+    let mut alternatives = vec![parse_and(tokens, pos)?];
+    while tokens.get(*pos) == Some(&Token::Or) {
+        *pos += 1;
+        alternatives.push(parse_and(tokens, pos)?);
+    }
+    Ok(if alternatives.len() == 1 {
+        alternatives.remove(0)
+    } else {
+        SymbolFilter::Any(alternatives)
+    })
+}
+
+fn parse_and(tokens: &[Token], pos: &mut usize) -> Result<SymbolFilter, String> {
+    let mut terms = Vec::new();
+    while let Some(token) = tokens.get(*pos) {
+        if matches!(token, Token::Or | Token::Close) {
+            break;
+        }
+        terms.push(parse_unary(tokens, pos)?);
+    }
+    if terms.is_empty() {
+        return Err("Empty expression in symbol query.".to_string());
+    }
+    Ok(if terms.len() == 1 {
+        terms.remove(0)
+    } else {
+        SymbolFilter::All(terms)
+    })
+}
+
+fn parse_unary(tokens: &[Token], pos: &mut usize) -> Result<SymbolFilter, String> {
+    let token = tokens.get(*pos).cloned();
+    *pos += 1;
+    match token {
+        Some(Token::Not) => Ok(SymbolFilter::Not(Box::new(parse_unary(tokens, pos)?))),
+        Some(Token::Open) => {
+            let inner = parse_or(tokens, pos)?;
+            if tokens.get(*pos) != Some(&Token::Close) {
+                return Err("Missing ')' in symbol query.".to_string());
+            }
+            *pos += 1;
+            Ok(inner)
+        }
+        Some(Token::Term(term)) => parse_term(&term),
+        _ => Err("Unexpected end of symbol query.".to_string()),
+    }
+}
+
+fn parse_term(term: &str) -> Result<SymbolFilter, String> {
+    let field_end = term.find([':', '<', '>', '=']).filter(|idx| {
+        *idx > 0 && term[..*idx].chars().all(|c| c.is_ascii_alphabetic())
+    });
+    let (field, rest) = match field_end {
+        Some(idx) => (term[..idx].to_ascii_lowercase(), &term[idx..]),
+        None => return Ok(SymbolFilter::Text(TextField::Name, text_match(TextField::Name, term)?)),
+    };
+    let text_field = match field.as_str() {
+        "name" | "n" => Some(TextField::Name),
+        "section" | "sec" | "s" => Some(TextField::Section),
+        "class" | "c" => Some(TextField::Class),
+        "kind" | "type" | "k" => Some(TextField::Kind),
+        "object" | "obj" | "o" => Some(TextField::Object),
+        "library" | "lib" | "l" => Some(TextField::Library),
+        _ => None,
+    };
+    if let Some(text_field) = text_field {
+        let value = rest
+            .strip_prefix(':')
+            .ok_or_else(|| format!("Use {}:<value> in symbol query term {}.", field, term))?;
+        return Ok(SymbolFilter::Text(text_field, text_match(text_field, value)?));
+    }
+    let range_field = match field.as_str() {
+        "size" => RangeField::Size,
+        "addr" | "address" => RangeField::Addr,
+        // Not a field after all: a C++ scope in `ns::func` or an operator such as `operator<`.
+        _ if rest.starts_with("::") || rest.chars().all(|c| matches!(c, '<' | '>' | '=')) => {
+            return Ok(SymbolFilter::Text(TextField::Name, text_match(TextField::Name, term)?))
+        }
+        _ => return Err(format!("Unknown field {} in symbol query.", field)),
+    };
+    let (min, max) = parse_range(rest).ok_or_else(|| format!("Invalid range in symbol query term {}.", term))?;
+    Ok(SymbolFilter::Range(range_field, min, max))
+}
+
+fn text_match(field: TextField, value: &str) -> Result<TextMatch, String> {
+    let value = value.trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value);
+    if value.is_empty() {
+        return Err("Empty value in symbol query.".to_string());
+    }
+    if let Some(pattern) = value.strip_prefix('/').and_then(|v| v.strip_suffix('/')) {
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| format!("Invalid regex /{}/ in symbol query: {}", pattern, e))?;
+        return Ok(TextMatch::Pattern(regex));
+    }
+    if value.contains(['*', '?']) {
+        return Ok(TextMatch::Glob(glob_regex(&normalize(field, value))?));
+    }
+    Ok(match field {
+        TextField::Section | TextField::Class | TextField::Kind => TextMatch::OneOf(
+            value
+                .split(',')
+                .map(|part| normalize(field, part))
+                .filter(|part| !part.is_empty())
+                .collect(),
+        ),
+        _ => TextMatch::Contains(value.to_ascii_lowercase()),
+    })
+}
+
+/// Globs match the whole value, case-insensitively.
+pub fn glob_regex(glob: &str) -> Result<Regex, String> {
+    let mut pattern = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            _ => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    RegexBuilder::new(&pattern)
+        .case_insensitive(true)
+        .build()
+        .map_err(|e| format!("Invalid glob {}: {}", glob, e))
+}
+
+fn parse_range(rest: &str) -> Option<(u64, u64)> {
+    let (op, value) = ["<=", ">=", "<", ">", "=", ":"]
+        .iter()
+        .find_map(|op| rest.strip_prefix(op).map(|value| (*op, value)))?;
+    if op == ":" {
+        if let Some((min, max)) = value.split_once("..") {
+            let min = if min.is_empty() { 0 } else { parse_quantity(min)? };
+            let max = if max.is_empty() { u64::MAX } else { parse_quantity(max)? };
+            return Some((min, max));
+        }
+    }
+    let value = parse_quantity(value)?;
+    Some(match op {
+        "<=" => (0, value),
+        ">=" => (value, u64::MAX),
+        "<" => (0, value.checked_sub(1)?),
+        ">" => (value.checked_add(1)?, u64::MAX),
+        _ => (value, value),
+    })
+}
+
+/// Decimal or `0x` hex, with optional `_` separators and a k/M/G suffix (powers of 1024).
+fn parse_quantity(text: &str) -> Option<u64> {
+    let text = text.trim().replace('_', "");
+    let lower = text.to_ascii_lowercase();
+    if let Some(hex) = lower.strip_prefix("0x") {
+        return u64::from_str_radix(hex, 16).ok();
+    }
+    let digits = lower.trim_end_matches('b');
+    let (number, scale) = match digits.chars().last()? {
+        'k' => (&digits[..digits.len() - 1], 1u64 << 10),
+        'm' => (&digits[..digits.len() - 1], 1u64 << 20),
+        'g' => (&digits[..digits.len() - 1], 1u64 << 30),
+        _ => (digits, 1),
+    };
+    if let Ok(value) = number.parse::<u64>() {
+        return value.checked_mul(scale);
+    }
+    let value = number.parse::<f64>().ok()?;
+    (value >= 0.0).then_some((value * scale as f64) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, size: u64, section: &str) -> SymbolInfo {
+        SymbolInfo {
+            name: name.to_string(),
+            size,
+            addr: Some("20000000".to_string()),
+            kind: "B".to_string(),
+            section_guess: section.trim_start_matches('.').to_string(),
+            section: Some(section.to_string()),
+            ..SymbolInfo::default()
+        }
+    }
+
+    #[test]
+    fn combines_size_section_and_negated_regex() {
+        let filter = SymbolFilter::parse("size>1k section:bss !name:/^_/").unwrap();
+        assert!(filter.matches(&symbol("rx_buffer", 2048, ".bss")));
+        assert!(!filter.matches(&symbol("_heap", 2048, ".bss")));
+        assert!(!filter.matches(&symbol("rx_buffer", 1024, ".bss")));
+        assert!(!filter.matches(&symbol("rx_buffer", 2048, ".data")));
+    }
+
+    #[test]
+    fn treats_cpp_scope_as_name() {
+        let filter = SymbolFilter::parse("ns::func").unwrap();
+        assert!(filter.matches(&symbol("ns::func(int)", 16, ".text")));
+        assert!(!filter.matches(&symbol("other::func", 16, ".text")));
+        assert_eq!(filter.required_name_substring(), Some("ns::func"));
+    }
+
+    #[test]
+    fn section_globs_ignore_the_leading_dot() {
+        let filter = SymbolFilter::parse("section:bss*").unwrap();
+        assert!(filter.matches(&symbol("rx_buffer", 16, ".bss")));
+        assert!(filter.matches(&symbol("rx_buffer", 16, ".bss.rx_buffer")));
+        assert!(!filter.matches(&symbol("rx_buffer", 16, ".data")));
+        assert!(SymbolFilter::parse("section:.bss*").unwrap().matches(&symbol("rx_buffer", 16, ".bss")));
+    }
+
+    #[test]
+    fn text_matching_ignores_case() {
+        let sym = symbol("HAL_UART_Transmit", 16, ".text");
+        for query in ["name:/^hal_uart/", "hal_uart*", "uart_trans", "name:/TRANSMIT$/"] {
+            assert!(SymbolFilter::parse(query).unwrap().matches(&sym), "{} should match", query);
+        }
+    }
+
+    #[test]
+    fn treats_cpp_operators_as_names() {
+        for (query, name) in [("operator<", "operator<(Foo const&)"), ("operator==", "Foo::operator==(Foo const&)")] {
+            let filter = SymbolFilter::parse(query).unwrap();
+            assert!(filter.matches(&symbol(name, 16, ".text")), "{} should match {}", query, name);
+        }
+        assert!(!SymbolFilter::parse("operator<").unwrap().matches(&symbol("operator>", 16, ".text")));
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        for query in ["foo:", "size>", "a=b", "(", "x|"] {
+            assert!(SymbolFilter::parse(query).is_err(), "{} should not parse", query);
+        }
+    }
+}
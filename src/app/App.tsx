@@ -21,6 +21,7 @@ import {
     AlertOutlined,
     ApartmentOutlined,
     DashboardOutlined,
+    FileSearchOutlined,
     PlayCircleOutlined,
     SettingOutlined,
     TableOutlined,
@@ -32,8 +33,11 @@ import ObjectsPage from "../pages/ObjectsPage";
 import SettingsPage from "../pages/SettingsPage";
 import SymbolsPage from "../pages/SymbolsPage";
 import LookupPage from "../pages/LookupPage";
+import SessionSwitcher from "../components/SessionSwitcher";
+import ImagesPage from "../pages/ImagesPage";
 import { uiText } from "../domain/uiI18n";
-import type {AnalyzeParams} from "../domain/analyzeTypes";
+import { type AnalyzeParams, regionThresholdParams, sectionRuleParams } from "../domain/analyzeTypes";
+import { closeReplacedSession } from "../domain/sessions";
 import {type ToolchainCandidate, deriveRootFromNm } from "../domain/toolchain";
 import { useAnalysisStore, type AnalysisResult } from "../store/analysis.store";
 import { useSettingsStore } from "../store/settings.store";
@@ -42,7 +46,7 @@ import "../App.css";
 
 const { Header, Content } = Layout;
 
-type PageKey = "dashboard" | "symbols" | "objects" | "findings" | "settings" | "lookup";
+type PageKey = "dashboard" | "symbols" | "objects" | "findings" | "settings" | "lookup" | "images";
 
 export default function App() {
     const [activePage, setActivePage] = useState<PageKey>("dashboard");
@@ -50,10 +54,14 @@ export default function App() {
     const isCompact = useMemo(() => !screens.md, [screens.md]);
     const toolchain = useSettingsStore((s) => s.toolchain);
     const updateToolchain = useSettingsStore((s) => s.updateToolchain);
+    const sectionRules = useSettingsStore((s) => s.sectionRules);
+    const regionThresholds = useSettingsStore((s) => s.regionThresholds);
+    const stackMarginBytes = useSettingsStore((s) => s.stackMarginBytes);
     const themeMode = useUiStore((s) => s.theme);
     const language = useUiStore((s) => s.language);
     const setTheme = useUiStore((s) => s.setTheme);
     const setLanguage = useUiStore((s) => s.setLanguage);
+    const svdPath = useAnalysisStore((s) => s.inputs.svdPath);
     const setInputs = useAnalysisStore((s) => s.setInputs);
     const setStatus = useAnalysisStore((s) => s.setStatus);
     const setResult = useAnalysisStore((s) => s.setResult);
@@ -71,6 +79,7 @@ export default function App() {
         findings: uiText(language, "pageFindings"),
         settings: uiText(language, "pageSettings"),
         lookup: uiText(language, "pageLookup"),
+        images: uiText(language, "pageImages"),
     };
 
     const menuItems = [
@@ -79,6 +88,7 @@ export default function App() {
         { key: "objects", label: uiText(language, "navObjects"), icon: <ApartmentOutlined /> },
         { key: "findings", label: uiText(language, "navFindings"), icon: <AlertOutlined /> },
         { key: "lookup", label: uiText(language, "navLookup"), icon: <AimOutlined /> },
+        { key: "images", label: uiText(language, "navImages"), icon: <FileSearchOutlined /> },
         { key: "settings", label: uiText(language, "navSettings"), icon: <SettingOutlined /> },
     ];
 
@@ -96,6 +106,8 @@ export default function App() {
                 return <SettingsPage />;
             case "lookup":
                 return <LookupPage />;
+            case "images":
+                return <ImagesPage />;
             default:
                 return null;
         }
@@ -190,6 +202,7 @@ export default function App() {
         const params: AnalyzeParams = {
             elf_path: elfPath,
             map_path: typeof mapPath === "string" ? mapPath : null,
+            svd_path: svdPath || null,
             toolchain: {
                 auto_detect: toolchain.autoDetect,
                 toolchain_root: toolchain.toolchainRoot || null,
@@ -197,11 +210,16 @@ export default function App() {
                 objdump_path: toolchain.objdumpPath || null,
                 strings_path: toolchain.stringsPath || null,
             },
+            region_thresholds: regionThresholdParams(regionThresholds),
+            stack_margin_bytes: stackMarginBytes,
+            section_rules: sectionRuleParams(sectionRules),
         };
 
         try {
+            const previous = useAnalysisStore.getState().result;
             const result = await invoke<AnalysisResult>("analyze_firmware", { params });
             setResult(result);
+            void closeReplacedSession(previous, result);
             setStatus("success");
             msgApi.success(uiText(language, "analysisStart"));
         } catch (error: unknown) {
@@ -242,6 +260,7 @@ export default function App() {
                                     ? uiText(language, "toolchainReady")
                                     : uiText(language, "toolchainMissing")}
                             </Tag>
+                            <SessionSwitcher />
                             <Space size="small" className="appHeaderSwitches">
                                 <span className="labelText">{uiText(language, "theme")}</span>
                                 <Switch
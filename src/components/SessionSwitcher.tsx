@@ -0,0 +1,72 @@
+﻿import { Button, Select, Space, Tooltip } from "antd";
+import { CloseOutlined } from "@ant-design/icons";
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { type SessionInfo, sessionLabel } from "../domain/sessions";
+import { uiText } from "../domain/uiI18n";
+import { type AnalysisResult, useAnalysisStore } from "../store/analysis.store";
+import { useUiStore } from "../store/ui.store";
+
+export default function SessionSwitcher() {
+    const language = useUiStore((s) => s.language);
+    const currentId = useAnalysisStore((s) => s.result?.meta.cache.key);
+    const setResult = useAnalysisStore((s) => s.setResult);
+    const setStatus = useAnalysisStore((s) => s.setStatus);
+    const [sessions, setSessions] = useState<SessionInfo[]>([]);
+
+    const refresh = async () => {
+        try {
+            setSessions(await invoke<SessionInfo[]>("list_sessions"));
+        } catch {
+            setSessions([]);
+        }
+    };
+
+    useEffect(() => {
+        void refresh();
+    }, [currentId]);
+
+    const switchTo = async (sessionId: string) => {
+        try {
+            const result = await invoke<AnalysisResult>("get_session", { sessionId });
+            setResult(result);
+            setStatus("success");
+        } catch (error: unknown) {
+            setStatus("error", error instanceof Error ? error.message : String(error));
+            await refresh();
+        }
+    };
+
+    const closeCurrent = async () => {
+        if (!currentId) return;
+        await invoke("close_session", { sessionId: currentId }).catch(() => undefined);
+        const next = sessions.find((session) => session.id !== currentId);
+        if (next) {
+            await switchTo(next.id);
+        } else {
+            setResult(null);
+            setStatus("idle");
+            setSessions([]);
+        }
+    };
+
+    if (sessions.length === 0) return null;
+
+    return (
+        <Space size="small" className="appHeaderSwitches">
+            <Select
+                value={currentId}
+                placeholder={uiText(language, "sessionSwitchPlaceholder")}
+                onChange={switchTo}
+                options={sessions.map((session) => ({ value: session.id, label: sessionLabel(session) }))}
+                popupMatchSelectWidth={false}
+                className="langSelect"
+            />
+            {currentId ? (
+                <Tooltip title={uiText(language, "sessionClose")}>
+                    <Button size="small" type="text" icon={<CloseOutlined />} onClick={closeCurrent} />
+                </Tooltip>
+            ) : null}
+        </Space>
+    );
+}
@@ -1,6 +1,9 @@
+import type { RegionThresholds, SectionRule } from "../store/settings.store";
+
 export type AnalyzeParams = {
     elf_path: string;
     map_path: string | null;
+    svd_path: string | null;
     toolchain: {
         auto_detect: boolean;
         toolchain_root: string | null;
@@ -8,4 +11,17 @@ export type AnalyzeParams = {
         objdump_path: string | null;
         strings_path: string | null;
     };
+    region_thresholds: { warn_percent: number; critical_percent: number };
+    stack_margin_bytes: number;
+    section_rules: { pattern: string; class: string; counts_against: string }[];
 };
+
+export const sectionRuleParams = (rules: SectionRule[]) =>
+    rules
+        .filter((rule) => rule.pattern.trim())
+        .map((rule) => ({ pattern: rule.pattern.trim(), class: rule.class, counts_against: rule.countsAgainst }));
+
+export const regionThresholdParams = (thresholds: RegionThresholds) => ({
+    warn_percent: thresholds.warnPercent,
+    critical_percent: thresholds.criticalPercent,
+});
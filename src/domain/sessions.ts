@@ -0,0 +1,22 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { AnalysisResult } from "../store/analysis.store";
+
+export type SessionInfo = {
+    id: string;
+    elf_path: string;
+    map_path?: string | null;
+    build_id?: string | null;
+    symbol_count: number;
+};
+
+export const sessionLabel = (session: SessionInfo) => {
+    const fileName = session.elf_path.split(/[\\/]/).pop() || session.elf_path;
+    return session.build_id ? `${fileName} (${session.build_id.slice(0, 8)})` : fileName;
+};
+
+// Re-analysing the same ELF with other inputs opens a new session; the old one is closed.
+export const closeReplacedSession = async (previous: AnalysisResult | null, next: AnalysisResult) => {
+    if (!previous || previous.meta.cache.key === next.meta.cache.key) return;
+    if (previous.meta.elf_path !== next.meta.elf_path) return;
+    await invoke("close_session", { sessionId: previous.meta.cache.key }).catch(() => undefined);
+};
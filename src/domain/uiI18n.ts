@@ -7,12 +7,14 @@ export type UiKey =
     | "navObjects"
     | "navFindings"
     | "navLookup"
+    | "navImages"
     | "navSettings"
     | "pageDashboard"
     | "pageSymbols"
     | "pageObjects"
     | "pageFindings"
     | "pageLookup"
+    | "pageImages"
     | "pageSettings"
     | "toolchainReady"
     | "toolchainMissing"
@@ -24,7 +26,10 @@ export type UiKey =
     | "analysisSelectMapTitle"
     | "analysisElfLabel"
     | "analysisMapLabel"
+    | "analysisSvdLabel"
     | "analysisNotSet"
+    | "analysisClearSvd"
+    | "analysisSelectSvdTitle"
     | "analysisNeedToolchain"
     | "analysisMissingElf"
     | "analysisRunCurrent"
@@ -47,9 +52,58 @@ export type UiKey =
     | "lookupNeedAnalysis"
     | "lookupNotFound"
     | "lookupOffset"
+    | "lookupPeripheralFields"
+    | "crashTitle"
+    | "crashHint"
+    | "crashPlaceholder"
+    | "crashMatch"
+    | "crashMatchedBy"
+    | "crashNoAddresses"
+    | "imageInspectTitle"
+    | "imageInspectHint"
+    | "imagePath"
+    | "imageBaseOptional"
+    | "imageInspectRun"
+    | "imageMatched"
+    | "imageNotMatched"
+    | "imageSection"
+    | "imageStatus"
+    | "imageFirstMismatch"
+    | "imageFindingMismatch"
+    | "imageFindingExtraBytes"
+    | "imageExportTitle"
+    | "imageExportHint"
+    | "imageFillByte"
+    | "imageFillGaps"
+    | "imagePadToRegion"
+    | "imageChecksumNone"
+    | "imageChecksumLocation"
+    | "imageExportRun"
+    | "imageWrittenBytes"
+    | "imageMultiTitle"
+    | "imageMultiHint"
+    | "imageSlotName"
+    | "imageSlot"
+    | "imageSlotAdd"
+    | "imageFreeBytes"
+    | "imageFindingOverlap"
+    | "imageFindingOutsideSlot"
+    | "ramDumpTitle"
+    | "ramDumpHint"
+    | "ramDumpPath"
+    | "ramDumpBase"
+    | "ramDumpPattern"
+    | "ramDumpRun"
+    | "ramDumpStack"
+    | "ramDumpRange"
+    | "ramDumpSize"
+    | "ramDumpUsed"
+    | "ramDumpNotInDump"
     | "autoDetectEnabled"
     | "manualToolchain"
     | "language"
+    | "sessionSwitchPlaceholder"
+    | "sessionClose"
     | "theme"
     | "themeLight"
     | "themeDark"
@@ -64,6 +118,22 @@ export type UiKey =
     | "settingsBrowse"
     | "settingsDetect"
     | "settingsReset"
+    | "settingsRemove"
+    | "settingsSectionRulesTitle"
+    | "settingsSectionRulesHint"
+    | "settingsSectionRuleFlash"
+    | "settingsSectionRuleRam"
+    | "settingsSectionRuleBoth"
+    | "settingsSectionRuleAdd"
+    | "settingsAnalysisTitle"
+    | "settingsAnalysisHint"
+    | "settingsRegionWarnPercent"
+    | "settingsRegionCriticalPercent"
+    | "settingsStackMargin"
+    | "settingsElfLibraryTitle"
+    | "settingsElfLibraryHint"
+    | "settingsElfLibraryAdd"
+    | "settingsElfLibraryCount"
     | "settingsUiTitle"
     | "settingsUiHint"
     | "dashFlashUsed"
@@ -96,9 +166,11 @@ export type UiKey =
     | "dashRegionDefaultSources"
     | "symbolsSearchPlaceholder"
     | "symbolsSectionPlaceholder"
+    | "symbolsClassPlaceholder"
     | "symbolsTypePlaceholder"
     | "symbolsApplyFilters"
     | "symbolsExport"
+    | "symbolsFuzzy"
     | "symbolsTableTitle"
     | "symbolsTableHint"
     | "symbolsColumnSymbol"
@@ -115,6 +187,7 @@ export type UiKey =
     | "symbolsDetailSize"
     | "symbolsDetailType"
     | "symbolsDetailSection"
+    | "symbolsDetailClass"
     | "objectsMapTitle"
     | "objectsMapHint"
     | "objectsMapEmpty"
@@ -143,7 +216,38 @@ export type UiKey =
     | "findingsRuleExidxTitle"
     | "findingsRuleExidxDetail"
     | "findingsRuleStringsTitle"
-    | "findingsRuleStringsDetail";
+    | "findingsRuleStringsDetail"
+    | "findingsRuleWeakHandlerTitle"
+    | "findingsRuleWeakHandlerDetail"
+    | "findingsRuleWeakOverrideTitle"
+    | "findingsRuleWeakOverrideDetail"
+    | "findingsRuleCommonDuplicateTitle"
+    | "findingsRuleCommonDuplicateDetail"
+    | "findingsRuleCommonTitle"
+    | "findingsRuleCommonDetail"
+    | "findingsRuleLinkageSkippedTitle"
+    | "findingsRuleLinkageSkippedDetail"
+    | "findingsRuleSectionOverlapTitle"
+    | "findingsRuleSectionOverlapDetail"
+    | "findingsRuleLayoutGapsTitle"
+    | "findingsRuleLayoutGapsDetail"
+    | "findingsRuleRegionOverflowTitle"
+    | "findingsRuleRegionOverflowDetail"
+    | "findingsRuleRegionCriticalTitle"
+    | "findingsRuleRegionCriticalDetail"
+    | "findingsRuleRegionHighTitle"
+    | "findingsRuleRegionHighDetail"
+    | "findingsRuleRegionGrowthTitle"
+    | "findingsRuleRegionGrowthDetail"
+    | "findingsRuleStackHeapTitle"
+    | "findingsRuleStackHeapDetail"
+    | "findingsRuleLiteralPoolTitle"
+    | "findingsRuleLiteralPoolDetail"
+    | "findingsRuleFloatAbiTitle"
+    | "findingsRuleFloatAbiDetail"
+    | "findingsRuleEnumSizeTitle"
+    | "findingsRuleEnumSizeDetail"
+    | "findingsOtherDetail";
 
 const zh: Record<UiKey, string> = {
     subtitle: "固件洞察控制台",
@@ -152,12 +256,14 @@ const zh: Record<UiKey, string> = {
     navObjects: "对象与映射",
     navFindings: "问题扫描",
     navLookup: "地址反查",
+    navImages: "固件镜像",
     navSettings: "设置",
     pageDashboard: "仪表盘",
     pageSymbols: "符号分析",
     pageObjects: "对象与映射",
     pageFindings: "问题扫描",
     pageLookup: "地址反查",
+    pageImages: "固件镜像",
     pageSettings: "设置",
     toolchainReady: "工具链已就绪",
     toolchainMissing: "工具链未配置",
@@ -169,7 +275,10 @@ const zh: Record<UiKey, string> = {
     analysisSelectMapTitle: "选择 MAP 文件（可选）",
     analysisElfLabel: "ELF",
     analysisMapLabel: "MAP",
+    analysisSvdLabel: "SVD",
     analysisNotSet: "未选择",
+    analysisClearSvd: "清除",
+    analysisSelectSvdTitle: "选择 SVD 文件（可选）",
     analysisNeedToolchain: "请先配置工具链路径",
     analysisMissingElf: "请先选择 ELF 文件",
     analysisRunCurrent: "使用当前输入分析",
@@ -192,9 +301,58 @@ const zh: Record<UiKey, string> = {
     lookupNeedAnalysis: "请先运行分析以加载符号表",
     lookupNotFound: "未找到匹配符号",
     lookupOffset: "偏移",
+    lookupPeripheralFields: "位域",
+    crashTitle: "崩溃日志解析",
+    crashHint: "粘贴崩溃日志或故障转储文本，按 build-id 或版本号匹配 ELF 库中的固件并解析其中的地址。",
+    crashPlaceholder: "粘贴日志，例如：HardFault PC=0x08001234 LR=0x08000f01",
+    crashMatch: "解析",
+    crashMatchedBy: "匹配方式：{by}",
+    crashNoAddresses: "日志中没有可解析的地址",
+    imageInspectTitle: "镜像校验",
+    imageInspectHint: "将 HEX、SREC 或 BIN 镜像与当前 ELF 的可加载段逐字节比对。",
+    imagePath: "镜像文件路径",
+    imageBaseOptional: "BIN 加载地址（可选）",
+    imageInspectRun: "校验",
+    imageMatched: "与 ELF 一致",
+    imageNotMatched: "与 ELF 不一致",
+    imageSection: "段",
+    imageStatus: "状态",
+    imageFirstMismatch: "首个差异",
+    imageFindingMismatch: "内容不一致",
+    imageFindingExtraBytes: "额外字节",
+    imageExportTitle: "导出烧录镜像",
+    imageExportHint: "从当前 ELF 的可加载段生成 HEX 或 BIN，可填充到内存区域末尾并写入 CRC-32 或 SHA-256 校验值。",
+    imageFillByte: "填充",
+    imageFillGaps: "填充段间空隙",
+    imagePadToRegion: "填充到区域末尾（可选）",
+    imageChecksumNone: "不写校验值",
+    imageChecksumLocation: "校验值位置：地址或符号名",
+    imageExportRun: "导出",
+    imageWrittenBytes: "写入 {bytes} 字节",
+    imageMultiTitle: "多镜像布局",
+    imageMultiHint: "检查 bootloader、应用等多个 ELF 在 Flash 中是否重叠，以及是否超出各自分配的区域。区域取自当前分析的内存布局。",
+    imageSlotName: "名称",
+    imageSlot: "分配区域",
+    imageSlotAdd: "添加镜像",
+    imageFreeBytes: "剩余 {bytes} 字节",
+    imageFindingOverlap: "镜像重叠",
+    imageFindingOutsideSlot: "超出分配区域",
+    ramDumpTitle: "RAM 转储分析",
+    ramDumpHint: "加载设备 RAM 的原始转储，按填充图案测量主栈和任务栈的最高水位。",
+    ramDumpPath: "转储文件路径",
+    ramDumpBase: "基地址",
+    ramDumpPattern: "填充",
+    ramDumpRun: "分析",
+    ramDumpStack: "栈",
+    ramDumpRange: "地址范围",
+    ramDumpSize: "大小",
+    ramDumpUsed: "已用",
+    ramDumpNotInDump: "不在转储范围内",
     autoDetectEnabled: "工具链自动探测已开启",
     manualToolchain: "手动配置工具链",
     language: "语言",
+    sessionSwitchPlaceholder: "已打开的分析",
+    sessionClose: "关闭当前分析",
     theme: "主题",
     themeLight: "浅色",
     themeDark: "深色",
@@ -209,6 +367,22 @@ const zh: Record<UiKey, string> = {
     settingsBrowse: "浏览",
     settingsDetect: "自动探测",
     settingsReset: "重置",
+    settingsRemove: "删除",
+    settingsSectionRulesTitle: "段分类规则",
+    settingsSectionRulesHint: "为自定义段（如 .ramfunc、.flash_config）指定分类及其计入 Flash、RAM 或两者；按顺序匹配，优先于内置的 .text/.rodata/.data/.bss 规则，重新分析后生效。",
+    settingsSectionRuleFlash: "Flash",
+    settingsSectionRuleRam: "RAM",
+    settingsSectionRuleBoth: "Flash + RAM",
+    settingsSectionRuleAdd: "添加规则",
+    settingsAnalysisTitle: "分析阈值",
+    settingsAnalysisHint: "内存区域使用率达到警告或严重阈值、或堆与栈之间的空闲空间低于余量时生成提示；下次分析时生效，不会使分析缓存失效。",
+    settingsRegionWarnPercent: "警告阈值",
+    settingsRegionCriticalPercent: "严重阈值",
+    settingsStackMargin: "栈/堆最小余量",
+    settingsElfLibraryTitle: "ELF 库",
+    settingsElfLibraryHint: "登记存放历史版本 ELF 的目录；粘贴崩溃日志时按 build-id 或版本号自动匹配对应的 ELF。",
+    settingsElfLibraryAdd: "添加目录",
+    settingsElfLibraryCount: "已索引 {count} 个 ELF",
     settingsUiTitle: "界面偏好",
     settingsUiHint: "默认语言为中文，可随时切换主题与语言。",
     dashFlashUsed: "Flash 已用",
@@ -239,11 +413,13 @@ const zh: Record<UiKey, string> = {
     dashRegionDefaultTag: "默认区域",
     dashRegionDefaultHint: "链接器兜底区域：未显式归属到 RAM/FLASH 的段会落在这里。",
     dashRegionDefaultSources: "占用来源",
-    symbolsSearchPlaceholder: "搜索符号名或正则",
+    symbolsSearchPlaceholder: "搜索符号，如 size>1k section:bss !name:/^_/",
     symbolsSectionPlaceholder: "段",
+    symbolsClassPlaceholder: "分类",
     symbolsTypePlaceholder: "类型",
     symbolsApplyFilters: "应用筛选",
     symbolsExport: "导出",
+    symbolsFuzzy: "模糊",
     symbolsTableTitle: "符号表",
     symbolsTableHint: "大 ELF 将使用分页与虚拟滚动保障性能。",
     symbolsColumnSymbol: "符号",
@@ -260,6 +436,7 @@ const zh: Record<UiKey, string> = {
     symbolsDetailSize: "大小",
     symbolsDetailType: "类型",
     symbolsDetailSection: "段",
+    symbolsDetailClass: "分类",
     objectsMapTitle: "贡献树图",
     objectsMapHint: "Treemap 展示库、对象文件与段的层级关系。",
     objectsMapEmpty: "MAP 解析后显示 Treemap。",
@@ -289,6 +466,37 @@ const zh: Record<UiKey, string> = {
     findingsRuleExidxDetail: "扫描 .ARM.exidx / .ARM.extab",
     findingsRuleStringsTitle: "字符串密度",
     findingsRuleStringsDetail: "统计字符串段与数量",
+    findingsRuleWeakHandlerTitle: "未覆盖的弱中断处理函数",
+    findingsRuleWeakHandlerDetail: "中断向量仍指向弱定义的默认处理函数",
+    findingsRuleWeakOverrideTitle: "弱符号覆盖",
+    findingsRuleWeakOverrideDetail: "弱定义被其他目标文件的强定义替换",
+    findingsRuleCommonDuplicateTitle: "重复的 COMMON 符号",
+    findingsRuleCommonDuplicateDetail: "多个目标文件定义了同名的 COMMON 变量",
+    findingsRuleCommonTitle: "COMMON 符号",
+    findingsRuleCommonDetail: "未初始化的全局变量以 COMMON 方式合并",
+    findingsRuleLinkageSkippedTitle: "未扫描的目标文件",
+    findingsRuleLinkageSkippedDetail: "nm 无法读取这些输入，弱符号与 COMMON 检查未包含它们",
+    findingsRuleSectionOverlapTitle: "段地址重叠",
+    findingsRuleSectionOverlapDetail: "有段占用了相同的地址范围",
+    findingsRuleLayoutGapsTitle: "布局空洞",
+    findingsRuleLayoutGapsDetail: "段之间存在无法由对齐解释的空隙",
+    findingsRuleRegionOverflowTitle: "内存区域溢出",
+    findingsRuleRegionOverflowDetail: "占用超过了区域容量",
+    findingsRuleRegionCriticalTitle: "内存区域接近满",
+    findingsRuleRegionCriticalDetail: "使用率达到严重阈值",
+    findingsRuleRegionHighTitle: "内存区域使用率高",
+    findingsRuleRegionHighDetail: "使用率达到警告阈值",
+    findingsRuleRegionGrowthTitle: "内存增长预测",
+    findingsRuleRegionGrowthDetail: "按近期增长速度，最先填满的区域还剩的构建次数",
+    findingsRuleStackHeapTitle: "栈/堆余量不足",
+    findingsRuleStackHeapDetail: "静态数据与堆之后到栈底的空闲空间低于设定余量",
+    findingsRuleLiteralPoolTitle: "文字池占比高",
+    findingsRuleLiteralPoolDetail: "函数体中内嵌常量数据占比较高的函数",
+    findingsRuleFloatAbiTitle: "浮点 ABI 不一致",
+    findingsRuleFloatAbiDetail: "与多数目标文件浮点调用约定不同的目标文件数",
+    findingsRuleEnumSizeTitle: "枚举大小不一致",
+    findingsRuleEnumSizeDetail: "与多数目标文件枚举大小设置不同的目标文件数",
+    findingsOtherDetail: "其他分析结果",
 };
 
 const en: Record<UiKey, string> = {
@@ -298,12 +506,14 @@ const en: Record<UiKey, string> = {
     navObjects: "Objects & Map",
     navFindings: "Findings",
     navLookup: "Lookup",
+    navImages: "Images",
     navSettings: "Settings",
     pageDashboard: "Dashboard",
     pageSymbols: "Symbols",
     pageObjects: "Objects & Map",
     pageFindings: "Findings",
     pageLookup: "Lookup",
+    pageImages: "Firmware Images",
     pageSettings: "Settings",
     toolchainReady: "Toolchain Ready",
     toolchainMissing: "Toolchain Missing",
@@ -315,7 +525,10 @@ const en: Record<UiKey, string> = {
     analysisSelectMapTitle: "Select MAP file (optional)",
     analysisElfLabel: "ELF",
     analysisMapLabel: "MAP",
+    analysisSvdLabel: "SVD",
     analysisNotSet: "Not set",
+    analysisClearSvd: "Clear",
+    analysisSelectSvdTitle: "Select SVD file (optional)",
     analysisNeedToolchain: "Please configure the toolchain first.",
     analysisMissingElf: "Please select an ELF file.",
     analysisRunCurrent: "Analyze current inputs",
@@ -338,9 +551,58 @@ const en: Record<UiKey, string> = {
     lookupNeedAnalysis: "Please run analysis to load symbols",
     lookupNotFound: "No matching symbol found",
     lookupOffset: "Offset",
+    lookupPeripheralFields: "Fields",
+    crashTitle: "Crash Log",
+    crashHint: "Paste a crash log or fault dump. The firmware is matched from the ELF library by build-id or version and every address is resolved against it.",
+    crashPlaceholder: "Paste the log, e.g. HardFault PC=0x08001234 LR=0x08000f01",
+    crashMatch: "Resolve",
+    crashMatchedBy: "Matched by: {by}",
+    crashNoAddresses: "No resolvable addresses in the log",
+    imageInspectTitle: "Inspect Image",
+    imageInspectHint: "Compare a HEX, SREC or BIN image byte for byte with the loadable sections of the current ELF.",
+    imagePath: "Image file path",
+    imageBaseOptional: "BIN load address (optional)",
+    imageInspectRun: "Inspect",
+    imageMatched: "Matches the ELF",
+    imageNotMatched: "Differs from the ELF",
+    imageSection: "Section",
+    imageStatus: "Status",
+    imageFirstMismatch: "First mismatch",
+    imageFindingMismatch: "Content mismatch",
+    imageFindingExtraBytes: "Extra bytes",
+    imageExportTitle: "Export Flash Image",
+    imageExportHint: "Build a HEX or BIN from the loadable segments of the current ELF, optionally padded to the end of a memory region with a CRC-32 or SHA-256 written into the image.",
+    imageFillByte: "Fill",
+    imageFillGaps: "Fill gaps",
+    imagePadToRegion: "Pad to region end (optional)",
+    imageChecksumNone: "No checksum",
+    imageChecksumLocation: "Checksum location: address or symbol",
+    imageExportRun: "Export",
+    imageWrittenBytes: "{bytes} bytes written",
+    imageMultiTitle: "Multi-Image Layout",
+    imageMultiHint: "Check that several ELFs such as a bootloader and an application do not overlap in flash and stay inside their slots. Slots are regions of the current analysis.",
+    imageSlotName: "Name",
+    imageSlot: "Slot",
+    imageSlotAdd: "Add image",
+    imageFreeBytes: "{bytes} bytes free",
+    imageFindingOverlap: "Images overlap",
+    imageFindingOutsideSlot: "Outside slot",
+    ramDumpTitle: "RAM Dump",
+    ramDumpHint: "Load a raw RAM dump from the device to measure the high-water mark of the main and task stacks from their fill pattern.",
+    ramDumpPath: "Dump file path",
+    ramDumpBase: "Base",
+    ramDumpPattern: "Fill",
+    ramDumpRun: "Analyze",
+    ramDumpStack: "Stack",
+    ramDumpRange: "Range",
+    ramDumpSize: "Size",
+    ramDumpUsed: "Used",
+    ramDumpNotInDump: "Not covered by the dump",
     autoDetectEnabled: "Toolchain auto-detect enabled",
     manualToolchain: "Manual toolchain",
     language: "Language",
+    sessionSwitchPlaceholder: "Open analyses",
+    sessionClose: "Close this analysis",
     theme: "Theme",
     themeLight: "Light",
     themeDark: "Dark",
@@ -355,6 +617,22 @@ const en: Record<UiKey, string> = {
     settingsBrowse: "Browse",
     settingsDetect: "Auto-detect",
     settingsReset: "Reset",
+    settingsRemove: "Remove",
+    settingsSectionRulesTitle: "Section Classification Rules",
+    settingsSectionRulesHint: "Classify custom sections such as .ramfunc or .flash_config and choose whether they count against flash, RAM or both. Rules match in order before the built-in .text/.rodata/.data/.bss prefixes and apply on the next analysis.",
+    settingsSectionRuleFlash: "Flash",
+    settingsSectionRuleRam: "RAM",
+    settingsSectionRuleBoth: "Flash + RAM",
+    settingsSectionRuleAdd: "Add rule",
+    settingsAnalysisTitle: "Analysis Thresholds",
+    settingsAnalysisHint: "Region usage at or above these percentages is reported as high or critical, and free RAM between heap and stack below the margin is flagged. Applied on the next analysis without invalidating the cache.",
+    settingsRegionWarnPercent: "Warning threshold",
+    settingsRegionCriticalPercent: "Critical threshold",
+    settingsStackMargin: "Minimum stack/heap margin",
+    settingsElfLibraryTitle: "ELF Library",
+    settingsElfLibraryHint: "Register directories holding released ELF files. Pasted crash logs are matched to an ELF by build-id or version string.",
+    settingsElfLibraryAdd: "Add directory",
+    settingsElfLibraryCount: "{count} ELF files indexed",
     settingsUiTitle: "UI Preferences",
     settingsUiHint: "Default language is Chinese. Switch theme or language anytime.",
     dashFlashUsed: "Flash Used",
@@ -385,11 +663,13 @@ const en: Record<UiKey, string> = {
     dashRegionDefaultTag: "Default",
     dashRegionDefaultHint: "Linker fallback region for sections not mapped to RAM/FLASH.",
     dashRegionDefaultSources: "Sources",
-    symbolsSearchPlaceholder: "Search symbol name or regex",
+    symbolsSearchPlaceholder: "Search symbols, e.g. size>1k section:bss !name:/^_/",
     symbolsSectionPlaceholder: "Section",
+    symbolsClassPlaceholder: "Class",
     symbolsTypePlaceholder: "Type",
     symbolsApplyFilters: "Apply Filters",
     symbolsExport: "Export",
+    symbolsFuzzy: "Fuzzy",
     symbolsTableTitle: "Symbols Table",
     symbolsTableHint: "Pagination and virtual scrolling keep large ELF files fast.",
     symbolsColumnSymbol: "Symbol",
@@ -406,6 +686,7 @@ const en: Record<UiKey, string> = {
     symbolsDetailSize: "Size",
     symbolsDetailType: "Type",
     symbolsDetailSection: "Section",
+    symbolsDetailClass: "Class",
     objectsMapTitle: "Contribution Map",
     objectsMapHint: "Treemap view of libraries, object files, and sections.",
     objectsMapEmpty: "Treemap will appear after MAP parsing.",
@@ -435,6 +716,37 @@ const en: Record<UiKey, string> = {
     findingsRuleExidxDetail: "Looks for .ARM.exidx / .ARM.extab",
     findingsRuleStringsTitle: "String density",
     findingsRuleStringsDetail: "Counts string sections",
+    findingsRuleWeakHandlerTitle: "Un-overridden weak ISRs",
+    findingsRuleWeakHandlerDetail: "Vectors still pointing at the weak default handler",
+    findingsRuleWeakOverrideTitle: "Weak symbol overrides",
+    findingsRuleWeakOverrideDetail: "Weak definitions replaced by a strong one from another object",
+    findingsRuleCommonDuplicateTitle: "Duplicate COMMON symbols",
+    findingsRuleCommonDuplicateDetail: "The same COMMON variable is defined by several objects",
+    findingsRuleCommonTitle: "COMMON symbols",
+    findingsRuleCommonDetail: "Uninitialised globals merged as COMMON",
+    findingsRuleLinkageSkippedTitle: "Unscanned objects",
+    findingsRuleLinkageSkippedDetail: "nm could not read these inputs, so the weak and COMMON checks leave them out",
+    findingsRuleSectionOverlapTitle: "Overlapping sections",
+    findingsRuleSectionOverlapDetail: "Sections occupy the same address range",
+    findingsRuleLayoutGapsTitle: "Layout gaps",
+    findingsRuleLayoutGapsDetail: "Holes between sections not explained by alignment",
+    findingsRuleRegionOverflowTitle: "Region overflow",
+    findingsRuleRegionOverflowDetail: "Usage exceeds the region length",
+    findingsRuleRegionCriticalTitle: "Region nearly full",
+    findingsRuleRegionCriticalDetail: "Usage at or above the critical threshold",
+    findingsRuleRegionHighTitle: "High region usage",
+    findingsRuleRegionHighDetail: "Usage at or above the warning threshold",
+    findingsRuleRegionGrowthTitle: "Region growth",
+    findingsRuleRegionGrowthDetail: "Builds left before the fastest-growing region fills up",
+    findingsRuleStackHeapTitle: "Stack/heap margin",
+    findingsRuleStackHeapDetail: "Free RAM between heap and stack is below the configured margin",
+    findingsRuleLiteralPoolTitle: "Heavy literal pools",
+    findingsRuleLiteralPoolDetail: "Functions whose bodies are largely inline constant data",
+    findingsRuleFloatAbiTitle: "Float ABI mismatch",
+    findingsRuleFloatAbiDetail: "Objects built with a different float calling convention than the majority",
+    findingsRuleEnumSizeTitle: "Enum size mismatch",
+    findingsRuleEnumSizeDetail: "Objects built with a different enum size than the majority",
+    findingsOtherDetail: "Additional analysis result",
 };
 
 const dictionaries: Record<Language, Record<UiKey, string>> = { zh, en };
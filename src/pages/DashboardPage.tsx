@@ -2,8 +2,10 @@
 import { useState } from "react";
 import { InfoCircleOutlined } from "@ant-design/icons";
 import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
 import { uiText } from "../domain/uiI18n";
-import type { AnalyzeParams } from "../domain/analyzeTypes";
+import { type AnalyzeParams, regionThresholdParams, sectionRuleParams } from "../domain/analyzeTypes";
+import { closeReplacedSession } from "../domain/sessions";
 import { type ToolchainCandidate, deriveRootFromNm } from "../domain/toolchain";
 import { useAnalysisStore } from "../store/analysis.store";
 import type { AnalysisResult } from "../store/analysis.store";
@@ -35,6 +37,7 @@ const statusColor = (status: string) => {
 export default function DashboardPage() {
     const language = useUiStore((s) => s.language);
     const inputs = useAnalysisStore((s) => s.inputs);
+    const setInputs = useAnalysisStore((s) => s.setInputs);
     const result = useAnalysisStore((s) => s.result);
     const status = useAnalysisStore((s) => s.status);
     const lastError = useAnalysisStore((s) => s.lastError);
@@ -42,6 +45,9 @@ export default function DashboardPage() {
     const setResult = useAnalysisStore((s) => s.setResult);
     const toolchain = useSettingsStore((s) => s.toolchain);
     const updateToolchain = useSettingsStore((s) => s.updateToolchain);
+    const sectionRules = useSettingsStore((s) => s.sectionRules);
+    const regionThresholds = useSettingsStore((s) => s.regionThresholds);
+    const stackMarginBytes = useSettingsStore((s) => s.stackMarginBytes);
     const [showUsedBytes, setShowUsedBytes] = useState(false);
     const [usageBasis, setUsageBasis] = useState<"vma" | "ld">("vma");
     const [msgApi, contextHolder] = message.useMessage();
@@ -87,7 +93,13 @@ export default function DashboardPage() {
             render: (value: number) => formatBytes(value),
             width: 120,
         },
-        { title: uiText(language, "symbolsColumnSection"), dataIndex: "section_guess", key: "section", width: 120 },
+        {
+            title: uiText(language, "symbolsColumnSection"),
+            dataIndex: "section",
+            key: "section",
+            width: 120,
+            render: (value: string | undefined, record: { section_guess?: string }) => value || record.section_guess || "--",
+        },
     ];
 
     const estimateRegionUsed = (name: string) => {
@@ -161,6 +173,7 @@ export default function DashboardPage() {
         const params: AnalyzeParams = {
             elf_path: inputs.elfPath,
             map_path: inputs.mapPath || null,
+            svd_path: inputs.svdPath || null,
             toolchain: {
                 auto_detect: toolchain.autoDetect,
                 toolchain_root: toolchain.toolchainRoot || null,
@@ -168,11 +181,15 @@ export default function DashboardPage() {
                 objdump_path: toolchain.objdumpPath || null,
                 strings_path: toolchain.stringsPath || null,
             },
+            region_thresholds: regionThresholdParams(regionThresholds),
+            stack_margin_bytes: stackMarginBytes,
+            section_rules: sectionRuleParams(sectionRules),
         };
 
         try {
             const analysisResult = await invoke<AnalysisResult>("analyze_firmware", { params });
             setResult(analysisResult);
+            void closeReplacedSession(result, analysisResult);
             setStatus("success");
             msgApi.success(uiText(language, "analysisStart"));
         } catch (error: unknown) {
@@ -281,6 +298,18 @@ export default function DashboardPage() {
 
     const canRunCurrent = Boolean(inputs.elfPath) && status !== "running";
 
+    const browseSvd = async () => {
+        const selected = await open({
+            title: uiText(language, "analysisSelectSvdTitle"),
+            multiple: false,
+            filters: [{ name: "SVD", extensions: ["svd", "xml"] }],
+        });
+        const svdPath = Array.isArray(selected) ? selected[0] : selected;
+        if (svdPath && typeof svdPath === "string") {
+            setInputs({ svdPath });
+        }
+    };
+
     return (
         <>
             {contextHolder}
@@ -339,6 +368,22 @@ export default function DashboardPage() {
                                                                 {inputs.mapPath || uiText(language, "analysisNotSet")}
                                                             </Typography.Text>
                                                         </div>
+                                                        <div className="pathRow">
+                                                            <Typography.Text strong>{uiText(language, "analysisSvdLabel")}</Typography.Text>
+                                                            <Space size="small" wrap>
+                                                                <Typography.Text className="pathValue">
+                                                                    {inputs.svdPath || uiText(language, "analysisNotSet")}
+                                                                </Typography.Text>
+                                                                <Typography.Link onClick={browseSvd}>
+                                                                    {uiText(language, "settingsBrowse")}
+                                                                </Typography.Link>
+                                                                {inputs.svdPath ? (
+                                                                    <Typography.Link onClick={() => setInputs({ svdPath: "" })}>
+                                                                        {uiText(language, "analysisClearSvd")}
+                                                                    </Typography.Link>
+                                                                ) : null}
+                                                            </Space>
+                                                        </div>
                                                     </Space>
                                                 </Card>
                     </Col>
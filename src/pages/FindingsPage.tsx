@@ -24,6 +24,91 @@ const rules: RuleConfig[] = [
     { id: "FLOAT_BLOAT", titleKey: "findingsRuleFloatTitle", detailKey: "findingsRuleFloatDetail", valueKind: "bytes" },
     { id: "EXIDX", titleKey: "findingsRuleExidxTitle", detailKey: "findingsRuleExidxDetail", valueKind: "bytes" },
     { id: "STRING_COUNT", titleKey: "findingsRuleStringsTitle", detailKey: "findingsRuleStringsDetail", valueKind: "count" },
+    {
+        id: "WEAK_DEFAULT_HANDLER",
+        titleKey: "findingsRuleWeakHandlerTitle",
+        detailKey: "findingsRuleWeakHandlerDetail",
+        valueKind: "count",
+    },
+    {
+        id: "WEAK_OVERRIDE",
+        titleKey: "findingsRuleWeakOverrideTitle",
+        detailKey: "findingsRuleWeakOverrideDetail",
+        valueKind: "count",
+    },
+    {
+        id: "COMMON_DUPLICATE",
+        titleKey: "findingsRuleCommonDuplicateTitle",
+        detailKey: "findingsRuleCommonDuplicateDetail",
+        valueKind: "count",
+    },
+    { id: "COMMON_SYMBOLS", titleKey: "findingsRuleCommonTitle", detailKey: "findingsRuleCommonDetail", valueKind: "bytes" },
+    {
+        id: "LINKAGE_OBJECTS_SKIPPED",
+        titleKey: "findingsRuleLinkageSkippedTitle",
+        detailKey: "findingsRuleLinkageSkippedDetail",
+        valueKind: "count",
+    },
+    {
+        id: "SECTION_OVERLAP",
+        titleKey: "findingsRuleSectionOverlapTitle",
+        detailKey: "findingsRuleSectionOverlapDetail",
+        valueKind: "count",
+    },
+    {
+        id: "LAYOUT_GAPS",
+        titleKey: "findingsRuleLayoutGapsTitle",
+        detailKey: "findingsRuleLayoutGapsDetail",
+        valueKind: "bytes",
+    },
+    {
+        id: "REGION_OVERFLOW",
+        titleKey: "findingsRuleRegionOverflowTitle",
+        detailKey: "findingsRuleRegionOverflowDetail",
+        valueKind: "count",
+    },
+    {
+        id: "REGION_CRITICAL",
+        titleKey: "findingsRuleRegionCriticalTitle",
+        detailKey: "findingsRuleRegionCriticalDetail",
+        valueKind: "count",
+    },
+    {
+        id: "REGION_HIGH_USAGE",
+        titleKey: "findingsRuleRegionHighTitle",
+        detailKey: "findingsRuleRegionHighDetail",
+        valueKind: "count",
+    },
+    {
+        id: "REGION_GROWTH",
+        titleKey: "findingsRuleRegionGrowthTitle",
+        detailKey: "findingsRuleRegionGrowthDetail",
+        valueKind: "count",
+    },
+    {
+        id: "STACK_HEAP_MARGIN",
+        titleKey: "findingsRuleStackHeapTitle",
+        detailKey: "findingsRuleStackHeapDetail",
+        valueKind: "bytes",
+    },
+    {
+        id: "LITERAL_POOL_HEAVY",
+        titleKey: "findingsRuleLiteralPoolTitle",
+        detailKey: "findingsRuleLiteralPoolDetail",
+        valueKind: "bytes",
+    },
+    {
+        id: "FLOAT_ABI_MISMATCH",
+        titleKey: "findingsRuleFloatAbiTitle",
+        detailKey: "findingsRuleFloatAbiDetail",
+        valueKind: "count",
+    },
+    {
+        id: "ENUM_SIZE_MISMATCH",
+        titleKey: "findingsRuleEnumSizeTitle",
+        detailKey: "findingsRuleEnumSizeDetail",
+        valueKind: "count",
+    },
 ];
 
 const severityLabel = (language: string, severity: string) => {
@@ -38,6 +123,9 @@ export default function FindingsPage() {
     const result = useAnalysisStore((s) => s.result);
     const findings = result?.summary.findings ?? [];
     const findingsById = new Map(findings.map((item) => [item.id, item]));
+    const knownIds = new Set(rules.map((rule) => rule.id));
+    // Findings without a dedicated card are still shown, titled by their id.
+    const otherFindings = findings.filter((item) => !knownIds.has(item.id));
 
     return (
         <Space direction="vertical" size="large" className="pageStack">
@@ -82,6 +170,27 @@ export default function FindingsPage() {
                         </Col>
                     );
                 })}
+                {otherFindings.map((finding, index) => (
+                    <Col xs={24} md={12} xl={8} key={finding.id}>
+                        <Card className="pageCard riseIn" style={{ animationDelay: `${(rules.length + index) * 60}ms` }}>
+                            <Space direction="vertical" size="small" className="findingCard">
+                                <Tag color={severityColor(finding.severity)}>{severityLabel(language, finding.severity)}</Tag>
+                                <Typography.Title level={5}>{finding.id}</Typography.Title>
+                                <Typography.Text type="secondary">{uiText(language, "findingsOtherDetail")}</Typography.Text>
+                                <div className="findingValue">
+                                    <Typography.Text type="secondary">{uiText(language, "findingsCount")}</Typography.Text>
+                                    <Typography.Text strong>{String(finding.value)}</Typography.Text>
+                                </div>
+                                {finding.items?.length ? (
+                                    <div className="findingItems">
+                                        <Typography.Text type="secondary">{uiText(language, "findingsItems")}</Typography.Text>
+                                        <Typography.Text>{finding.items.slice(0, 5).join(", ")}</Typography.Text>
+                                    </div>
+                                ) : null}
+                            </Space>
+                        </Card>
+                    </Col>
+                ))}
             </Row>
         </Space>
     );
@@ -0,0 +1,490 @@
+﻿import { Button, Card, Checkbox, Col, Divider, Input, InputNumber, Row, Select, Space, Table, Tag, Typography } from "antd";
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import { uiText } from "../domain/uiI18n";
+import { useAnalysisStore } from "../store/analysis.store";
+import { useUiStore } from "../store/ui.store";
+
+type Finding = {
+    id: string;
+    severity: string;
+    value: number;
+    items: string[];
+};
+
+type SectionCheck = {
+    name: string;
+    lma: string;
+    size: number;
+    status: string;
+    mismatched_bytes: number;
+    missing_bytes: number;
+    first_mismatch?: string | null;
+};
+
+type ImageReport = {
+    image_path: string;
+    format: string;
+    total_bytes: number;
+    entry?: string | null;
+    verification: {
+        matched: boolean;
+        compared_bytes: number;
+        mismatched_bytes: number;
+        missing_bytes: number;
+        extra_bytes: number;
+        sections: SectionCheck[];
+    };
+    findings: Finding[];
+};
+
+type ImageExportResult = {
+    output_path: string;
+    format: string;
+    start: string;
+    end: string;
+    size: number;
+    written_bytes: number;
+    fill_byte: number;
+    checksum?: { algorithm: string; address: string; value: string } | null;
+};
+
+type ImageSlot = {
+    name: string;
+    elfPath: string;
+    slotRegion?: string;
+};
+
+type ImageFootprint = {
+    name: string;
+    elf_path: string;
+    build_id?: string | null;
+    start: string;
+    end: string;
+    load_bytes: number;
+    slot?: { name: string; length: number; used: number; usage_percent: number; outside_bytes: number } | null;
+};
+
+type MultiImageReport = {
+    images: ImageFootprint[];
+    free_bytes: number;
+    findings: Finding[];
+};
+
+const findingTitleKeys: Record<string, Parameters<typeof uiText>[1]> = {
+    IMAGE_MISMATCH: "imageFindingMismatch",
+    IMAGE_EXTRA_BYTES: "imageFindingExtraBytes",
+    IMAGE_OVERLAP: "imageFindingOverlap",
+    IMAGE_OUTSIDE_SLOT: "imageFindingOutsideSlot",
+};
+
+const pickFile = async (extensions: string[]) => {
+    const selected = await open({ multiple: false, filters: [{ name: "Image", extensions }] });
+    const path = Array.isArray(selected) ? selected[0] : selected;
+    return path && typeof path === "string" ? path : null;
+};
+
+const statusColor = (status: string) => {
+    if (status === "match") return "green";
+    if (status === "partial") return "gold";
+    return "red";
+};
+
+export default function ImagesPage() {
+    const language = useUiStore((s) => s.language);
+    const analysisStatus = useAnalysisStore((s) => s.status);
+    const sessionId = useAnalysisStore((s) => s.result?.meta.cache.key);
+    const regions = useAnalysisStore((s) => s.result?.summary.memory_regions ?? []);
+    const [imagePath, setImagePath] = useState("");
+    const [imageBase, setImageBase] = useState("");
+    const [report, setReport] = useState<ImageReport | null>(null);
+    const [inspectError, setInspectError] = useState<string | null>(null);
+    const [exportFormat, setExportFormat] = useState<"bin" | "hex">("hex");
+    const [fillByte, setFillByte] = useState(0xff);
+    const [fillGaps, setFillGaps] = useState(false);
+    const [padRegion, setPadRegion] = useState<string | undefined>(undefined);
+    const [checksumAlgorithm, setChecksumAlgorithm] = useState<"none" | "crc32" | "sha256">("none");
+    const [checksumLocation, setChecksumLocation] = useState("");
+    const [exported, setExported] = useState<ImageExportResult | null>(null);
+    const [exportError, setExportError] = useState<string | null>(null);
+    const [slots, setSlots] = useState<ImageSlot[]>([
+        { name: "bootloader", elfPath: "" },
+        { name: "app", elfPath: "" },
+    ]);
+    const [multiReport, setMultiReport] = useState<MultiImageReport | null>(null);
+    const [multiError, setMultiError] = useState<string | null>(null);
+
+    const renderFindings = (findings: Finding[]) =>
+        findings.map((finding) => (
+            <Typography.Text key={finding.id}>
+                <Tag color={finding.severity === "warn" ? "orange" : "blue"}>
+                    {findingTitleKeys[finding.id] ? uiText(language, findingTitleKeys[finding.id]) : finding.id}
+                </Tag>
+                {finding.items.length > 0 ? finding.items.join(", ") : `${finding.value} B`}
+            </Typography.Text>
+        ));
+
+    const onInspect = async () => {
+        setInspectError(null);
+        setReport(null);
+        if (analysisStatus !== "success" || !sessionId) {
+            setInspectError(uiText(language, "lookupNeedAnalysis"));
+            return;
+        }
+        try {
+            const next = await invoke<ImageReport>("inspect_image", {
+                sessionId,
+                params: { image_path: imagePath.trim(), base_address: imageBase.trim() || null },
+            });
+            setReport(next);
+        } catch (err: any) {
+            setInspectError(err?.message || String(err));
+        }
+    };
+
+    const onExport = async () => {
+        setExportError(null);
+        setExported(null);
+        if (analysisStatus !== "success" || !sessionId) {
+            setExportError(uiText(language, "lookupNeedAnalysis"));
+            return;
+        }
+        const outputPath = await save({
+            filters: [{ name: exportFormat.toUpperCase(), extensions: [exportFormat] }],
+        });
+        if (!outputPath) return;
+        try {
+            const next = await invoke<ImageExportResult>("export_flash_image", {
+                sessionId,
+                params: {
+                    output_path: outputPath,
+                    format: exportFormat,
+                    fill_byte: fillByte,
+                    fill_gaps: fillGaps,
+                    pad_to_region: padRegion ?? null,
+                    checksum:
+                        checksumAlgorithm === "none"
+                            ? null
+                            : { algorithm: checksumAlgorithm, location: checksumLocation.trim() },
+                },
+            });
+            setExported(next);
+        } catch (err: any) {
+            setExportError(err?.message || String(err));
+        }
+    };
+
+    const updateSlot = (index: number, next: Partial<ImageSlot>) =>
+        setSlots(slots.map((slot, i) => (i === index ? { ...slot, ...next } : slot)));
+
+    const onAnalyzeImages = async () => {
+        setMultiError(null);
+        setMultiReport(null);
+        try {
+            const next = await invoke<MultiImageReport>("analyze_multi_image", {
+                params: {
+                    images: slots
+                        .filter((slot) => slot.elfPath.trim())
+                        .map((slot) => ({
+                            name: slot.name.trim() || slot.elfPath.trim(),
+                            elf_path: slot.elfPath.trim(),
+                            slot_region: slot.slotRegion ?? null,
+                            slot_origin: null,
+                            slot_length: null,
+                        })),
+                    linker_script_path: null,
+                    session_id: sessionId ?? null,
+                },
+            });
+            setMultiReport(next);
+        } catch (err: any) {
+            setMultiError(err?.message || String(err));
+        }
+    };
+
+    const footprintColumns = [
+        { title: uiText(language, "imageSlotName"), dataIndex: "name", key: "name" },
+        {
+            title: uiText(language, "ramDumpRange"),
+            key: "range",
+            render: (_: unknown, image: ImageFootprint) => `${image.start} - ${image.end}`,
+        },
+        {
+            title: uiText(language, "ramDumpSize"),
+            dataIndex: "load_bytes",
+            key: "load_bytes",
+            render: (bytes: number) => `${bytes} B`,
+        },
+        {
+            title: uiText(language, "imageSlot"),
+            key: "slot",
+            render: (_: unknown, image: ImageFootprint) =>
+                image.slot ? `${image.slot.name} ${image.slot.usage_percent.toFixed(1)}%` : "--",
+        },
+    ];
+
+    const sectionColumns = [
+        { title: uiText(language, "imageSection"), dataIndex: "name", key: "name" },
+        { title: "LMA", dataIndex: "lma", key: "lma" },
+        { title: uiText(language, "ramDumpSize"), dataIndex: "size", key: "size", render: (size: number) => `${size} B` },
+        {
+            title: uiText(language, "imageStatus"),
+            dataIndex: "status",
+            key: "status",
+            render: (status: string) => <Tag color={statusColor(status)}>{status}</Tag>,
+        },
+        {
+            title: uiText(language, "imageFirstMismatch"),
+            dataIndex: "first_mismatch",
+            key: "first_mismatch",
+            render: (value?: string | null) => value || "--",
+        },
+    ];
+
+    return (
+        <Space direction="vertical" size="large" className="pageStack">
+            <Card className="pageCard riseIn">
+                <Typography.Title level={4}>{uiText(language, "imageInspectTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "imageInspectHint")}</Typography.Text>
+                <Divider />
+                <Row gutter={[12, 12]}>
+                    <Col xs={24} md={14}>
+                        <Input
+                            value={imagePath}
+                            placeholder={uiText(language, "imagePath")}
+                            onChange={(e) => setImagePath(e.target.value)}
+                            addonAfter={
+                                <Typography.Link
+                                    onClick={async () => {
+                                        const path = await pickFile(["hex", "ihex", "srec", "s19", "s28", "s37", "mot", "bin"]);
+                                        if (path) setImagePath(path);
+                                    }}
+                                >
+                                    {uiText(language, "settingsBrowse")}
+                                </Typography.Link>
+                            }
+                        />
+                    </Col>
+                    <Col xs={24} md={7}>
+                        <Input
+                            value={imageBase}
+                            placeholder={uiText(language, "imageBaseOptional")}
+                            addonBefore={uiText(language, "ramDumpBase")}
+                            onChange={(e) => setImageBase(e.target.value)}
+                        />
+                    </Col>
+                    <Col xs={24} md={3}>
+                        <Button type="primary" onClick={onInspect} disabled={!imagePath.trim()}>
+                            {uiText(language, "imageInspectRun")}
+                        </Button>
+                    </Col>
+                </Row>
+                {inspectError ? (
+                    <Typography.Text type="danger" className="lookupError">
+                        {inspectError}
+                    </Typography.Text>
+                ) : null}
+                {report ? (
+                    <Space direction="vertical" size="small" className="lookupResult" style={{ width: "100%" }}>
+                        <Space size="small" wrap>
+                            <Tag color={report.verification.matched ? "green" : "red"}>
+                                {report.verification.matched
+                                    ? uiText(language, "imageMatched")
+                                    : uiText(language, "imageNotMatched")}
+                            </Tag>
+                            <Tag>{report.format}</Tag>
+                            <Tag>{report.total_bytes} B</Tag>
+                            {report.entry ? <Tag>entry {report.entry}</Tag> : null}
+                        </Space>
+                        {renderFindings(report.findings)}
+                        <Table
+                            size="small"
+                            rowKey={(section) => `${section.name}-${section.lma}`}
+                            columns={sectionColumns}
+                            dataSource={report.verification.sections}
+                            pagination={false}
+                        />
+                    </Space>
+                ) : null}
+            </Card>
+
+            <Card className="pageCard riseIn" style={{ animationDelay: "60ms" }}>
+                <Typography.Title level={4}>{uiText(language, "imageExportTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "imageExportHint")}</Typography.Text>
+                <Divider />
+                <Row gutter={[12, 12]} align="middle">
+                    <Col xs={12} md={4}>
+                        <Select
+                            value={exportFormat}
+                            onChange={setExportFormat}
+                            options={[
+                                { value: "hex", label: "Intel HEX" },
+                                { value: "bin", label: "BIN" },
+                            ]}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                    <Col xs={12} md={4}>
+                        <InputNumber
+                            value={fillByte}
+                            min={0}
+                            max={255}
+                            addonBefore={uiText(language, "imageFillByte")}
+                            onChange={(value) => setFillByte(value ?? 0xff)}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                    <Col xs={12} md={4}>
+                        <Checkbox
+                            checked={fillGaps || exportFormat === "bin"}
+                            disabled={exportFormat === "bin"}
+                            onChange={(e) => setFillGaps(e.target.checked)}
+                        >
+                            {uiText(language, "imageFillGaps")}
+                        </Checkbox>
+                    </Col>
+                    <Col xs={12} md={6}>
+                        <Select
+                            value={padRegion}
+                            allowClear
+                            placeholder={uiText(language, "imagePadToRegion")}
+                            onChange={setPadRegion}
+                            options={regions.map((region) => ({ value: region.name, label: region.name }))}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                    <Col xs={12} md={6}>
+                        <Select
+                            value={checksumAlgorithm}
+                            onChange={setChecksumAlgorithm}
+                            options={[
+                                { value: "none", label: uiText(language, "imageChecksumNone") },
+                                { value: "crc32", label: "CRC-32" },
+                                { value: "sha256", label: "SHA-256" },
+                            ]}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                    {checksumAlgorithm !== "none" ? (
+                        <Col xs={24} md={12}>
+                            <Input
+                                value={checksumLocation}
+                                placeholder={uiText(language, "imageChecksumLocation")}
+                                onChange={(e) => setChecksumLocation(e.target.value)}
+                            />
+                        </Col>
+                    ) : null}
+                    <Col xs={24} md={4}>
+                        <Button type="primary" onClick={onExport}>
+                            {uiText(language, "imageExportRun")}
+                        </Button>
+                    </Col>
+                </Row>
+                {exportError ? (
+                    <Typography.Text type="danger" className="lookupError">
+                        {exportError}
+                    </Typography.Text>
+                ) : null}
+                {exported ? (
+                    <Space direction="vertical" size="small" className="lookupResult">
+                        <Typography.Text className="pathValue">{exported.output_path}</Typography.Text>
+                        <Space size="small" wrap>
+                            <Tag>
+                                {exported.start} - {exported.end}
+                            </Tag>
+                            <Tag>{exported.size} B</Tag>
+                            <Tag>{uiText(language, "imageWrittenBytes", { bytes: exported.written_bytes })}</Tag>
+                            {exported.checksum ? (
+                                <Tag color="green">
+                                    {exported.checksum.algorithm} @ {exported.checksum.address}: {exported.checksum.value}
+                                </Tag>
+                            ) : null}
+                        </Space>
+                    </Space>
+                ) : null}
+            </Card>
+
+            <Card className="pageCard riseIn" style={{ animationDelay: "120ms" }}>
+                <Typography.Title level={4}>{uiText(language, "imageMultiTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "imageMultiHint")}</Typography.Text>
+                <Divider />
+                <Space direction="vertical" size="small" style={{ width: "100%" }}>
+                    {slots.map((slot, index) => (
+                        <Row gutter={[12, 12]} key={index} align="middle">
+                            <Col xs={24} md={5}>
+                                <Input
+                                    value={slot.name}
+                                    placeholder={uiText(language, "imageSlotName")}
+                                    onChange={(e) => updateSlot(index, { name: e.target.value })}
+                                />
+                            </Col>
+                            <Col xs={24} md={11}>
+                                <Input
+                                    value={slot.elfPath}
+                                    placeholder="ELF"
+                                    onChange={(e) => updateSlot(index, { elfPath: e.target.value })}
+                                    addonAfter={
+                                        <Typography.Link
+                                            onClick={async () => {
+                                                const path = await pickFile(["elf", "axf", "out"]);
+                                                if (path) updateSlot(index, { elfPath: path });
+                                            }}
+                                        >
+                                            {uiText(language, "settingsBrowse")}
+                                        </Typography.Link>
+                                    }
+                                />
+                            </Col>
+                            <Col xs={16} md={5}>
+                                <Select
+                                    value={slot.slotRegion}
+                                    allowClear
+                                    placeholder={uiText(language, "imageSlot")}
+                                    onChange={(value) => updateSlot(index, { slotRegion: value })}
+                                    options={regions.map((region) => ({ value: region.name, label: region.name }))}
+                                    style={{ width: "100%" }}
+                                />
+                            </Col>
+                            <Col xs={8} md={3}>
+                                <Button danger onClick={() => setSlots(slots.filter((_, i) => i !== index))}>
+                                    {uiText(language, "settingsRemove")}
+                                </Button>
+                            </Col>
+                        </Row>
+                    ))}
+                    <Space>
+                        <Button onClick={() => setSlots([...slots, { name: "", elfPath: "" }])}>
+                            {uiText(language, "imageSlotAdd")}
+                        </Button>
+                        <Button
+                            type="primary"
+                            onClick={onAnalyzeImages}
+                            disabled={!slots.some((slot) => slot.elfPath.trim())}
+                        >
+                            {uiText(language, "ramDumpRun")}
+                        </Button>
+                    </Space>
+                </Space>
+                {multiError ? (
+                    <Typography.Text type="danger" className="lookupError">
+                        {multiError}
+                    </Typography.Text>
+                ) : null}
+                {multiReport ? (
+                    <Space direction="vertical" size="small" className="lookupResult" style={{ width: "100%" }}>
+                        <Tag>{uiText(language, "imageFreeBytes", { bytes: multiReport.free_bytes })}</Tag>
+                        {renderFindings(multiReport.findings)}
+                        <Table
+                            size="small"
+                            rowKey={(image) => `${image.name}-${image.elf_path}`}
+                            columns={footprintColumns}
+                            dataSource={multiReport.images}
+                            pagination={false}
+                        />
+                    </Space>
+                ) : null}
+            </Card>
+        </Space>
+    );
+}
@@ -1,8 +1,10 @@
-﻿import { Card, Divider, Input, Space, Typography, Tag } from "antd";
+﻿import { Button, Card, Col, Divider, Input, Row, Space, Table, Typography, Tag } from "antd";
 import { useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
 import { uiText } from "../domain/uiI18n";
-import { useAnalysisStore } from "../store/analysis.store";
+import { type AnalysisResult, useAnalysisStore } from "../store/analysis.store";
+import { useSettingsStore } from "../store/settings.store";
 import { useUiStore } from "../store/ui.store";
 
 type PcLookupResult = {
@@ -13,32 +15,168 @@ type PcLookupResult = {
         size: number;
         kind: string;
         section_guess: string;
+        section?: string;
         offset: number;
     };
+    peripheral?: {
+        peripheral: string;
+        register?: string | null;
+        offset: number;
+        path: string;
+        fields: string[];
+    } | null;
+};
+
+type StackUsage = {
+    name: string;
+    start: string;
+    end: string;
+    size: number;
+    used?: number | null;
+    unused?: number | null;
+    usage_percent?: number | null;
+    in_dump: boolean;
+};
+
+type RamDumpReport = {
+    dump_path: string;
+    base_address: string;
+    dump_size: number;
+    fill_pattern: string;
+    stacks: StackUsage[];
+    findings: { id: string; severity: string; value: number; items: string[] }[];
+};
+
+type CrashMatch = {
+    matched_by: "build_id" | "version" | "current";
+    entry?: { elf_path: string; build_id?: string | null; version?: string | null } | null;
+    session_id?: string | null;
+    addresses: PcLookupResult[];
 };
 
 export default function LookupPage() {
     const language = useUiStore((s) => s.language);
     const analysisStatus = useAnalysisStore((s) => s.status);
+    const sessionId = useAnalysisStore((s) => s.result?.meta.cache.key);
+    const setAnalysisResult = useAnalysisStore((s) => s.setResult);
+    const setAnalysisStatus = useAnalysisStore((s) => s.setStatus);
+    const toolchain = useSettingsStore((s) => s.toolchain);
     const [value, setValue] = useState("");
     const [result, setResult] = useState<PcLookupResult | null>(null);
     const [error, setError] = useState<string | null>(null);
+    const [dumpPath, setDumpPath] = useState("");
+    const [dumpBase, setDumpBase] = useState("0x20000000");
+    const [fillPattern, setFillPattern] = useState("");
+    const [dumpReport, setDumpReport] = useState<RamDumpReport | null>(null);
+    const [dumpError, setDumpError] = useState<string | null>(null);
+    const [crashLog, setCrashLog] = useState("");
+    const [crashMatch, setCrashMatch] = useState<CrashMatch | null>(null);
+    const [crashError, setCrashError] = useState<string | null>(null);
+    const [crashRunning, setCrashRunning] = useState(false);
 
     const onSearch = async () => {
         setError(null);
         setResult(null);
-        if (analysisStatus !== "success") {
+        if (analysisStatus !== "success" || !sessionId) {
             setError(uiText(language, "lookupNeedAnalysis"));
             return;
         }
         try {
-            const res = await invoke<PcLookupResult>("lookup_pc", { address: value.trim() });
+            const res = await invoke<PcLookupResult>("lookup_pc", { sessionId, address: value.trim() });
             setResult(res);
         } catch (err: any) {
             setError(err?.message || String(err));
         }
     };
 
+    const onMatchCrash = async () => {
+        setCrashError(null);
+        setCrashMatch(null);
+        setCrashRunning(true);
+        try {
+            const match = await invoke<CrashMatch>("match_crash_log", {
+                params: {
+                    log: crashLog,
+                    toolchain: {
+                        auto_detect: toolchain.autoDetect,
+                        toolchain_root: toolchain.toolchainRoot || null,
+                        nm_path: toolchain.nmPath || null,
+                        objdump_path: toolchain.objdumpPath || null,
+                        strings_path: toolchain.stringsPath || null,
+                    },
+                    session_id: sessionId ?? null,
+                },
+            });
+            // The log may belong to another firmware; follow it so later lookups use the same ELF.
+            if (match.session_id && match.session_id !== sessionId) {
+                const analysis = await invoke<AnalysisResult>("get_session", { sessionId: match.session_id });
+                setAnalysisResult(analysis);
+                setAnalysisStatus("success");
+            }
+            setCrashMatch(match);
+        } catch (err: any) {
+            setCrashError(err?.message || String(err));
+        } finally {
+            setCrashRunning(false);
+        }
+    };
+
+    const describeAddress = (item: PcLookupResult) => {
+        if (item.symbol) return `${item.symbol.name}+0x${item.symbol.offset.toString(16)}`;
+        if (item.peripheral) return item.peripheral.path;
+        return uiText(language, "lookupNotFound");
+    };
+
+    const onBrowseDump = async () => {
+        const selected = await open({ multiple: false, filters: [{ name: "RAM dump", extensions: ["bin", "raw"] }] });
+        const path = Array.isArray(selected) ? selected[0] : selected;
+        if (path && typeof path === "string") {
+            setDumpPath(path);
+        }
+    };
+
+    const onAnalyzeDump = async () => {
+        setDumpError(null);
+        setDumpReport(null);
+        if (analysisStatus !== "success" || !sessionId) {
+            setDumpError(uiText(language, "lookupNeedAnalysis"));
+            return;
+        }
+        try {
+            const report = await invoke<RamDumpReport>("analyze_ram_dump", {
+                sessionId,
+                params: {
+                    dump_path: dumpPath.trim(),
+                    base_address: dumpBase.trim(),
+                    fill_pattern: fillPattern.trim() || null,
+                    stack_symbols: null,
+                    warn_percent: null,
+                },
+            });
+            setDumpReport(report);
+        } catch (err: any) {
+            setDumpError(err?.message || String(err));
+        }
+    };
+
+    const stackColumns = [
+        { title: uiText(language, "ramDumpStack"), dataIndex: "name", key: "name" },
+        {
+            title: uiText(language, "ramDumpRange"),
+            key: "range",
+            render: (_: unknown, stack: StackUsage) => `${stack.start} - ${stack.end}`,
+        },
+        { title: uiText(language, "ramDumpSize"), dataIndex: "size", key: "size", render: (size: number) => `${size} B` },
+        {
+            title: uiText(language, "ramDumpUsed"),
+            key: "used",
+            render: (_: unknown, stack: StackUsage) =>
+                stack.in_dump && stack.used != null
+                    ? `${stack.used} B (${(stack.usage_percent ?? 0).toFixed(1)}%)`
+                    : uiText(language, "ramDumpNotInDump"),
+        },
+    ];
+
     return (
         <Space direction="vertical" size="large" className="pageStack">
             <Card className="pageCard riseIn">
@@ -66,18 +204,138 @@ export default function LookupPage() {
                                     {uiText(language, "lookupOffset")}: +0x{result.symbol.offset.toString(16)}
                                 </Typography.Text>
                                 <Space size="small">
-                                    <Tag color="blue">{result.symbol.section_guess}</Tag>
+                                    <Tag color="blue">{result.symbol.section || result.symbol.section_guess}</Tag>
                                     <Tag>{result.symbol.kind}</Tag>
                                     <Tag>0x{result.symbol.addr}</Tag>
                                     <Tag>{result.symbol.size} B</Tag>
                                 </Space>
                             </Space>
-                        ) : (
+                        ) : result.peripheral ? null : (
                             <Typography.Text type="secondary">{uiText(language, "lookupNotFound")}</Typography.Text>
                         )}
+                        {result.peripheral ? (
+                            <Space direction="vertical" size="small">
+                                <Typography.Text strong>{result.peripheral.path}</Typography.Text>
+                                <Space size="small" wrap>
+                                    <Tag color="purple">{result.peripheral.peripheral}</Tag>
+                                    {result.peripheral.register ? <Tag>{result.peripheral.register}</Tag> : null}
+                                    <Tag>+0x{result.peripheral.offset.toString(16)}</Tag>
+                                </Space>
+                                {result.peripheral.fields.length > 0 ? (
+                                    <Typography.Text type="secondary">
+                                        {uiText(language, "lookupPeripheralFields")}: {result.peripheral.fields.join(", ")}
+                                    </Typography.Text>
+                                ) : null}
+                            </Space>
+                        ) : null}
                     </div>
                 ) : null}
             </Card>
+
+            <Card className="pageCard riseIn" style={{ animationDelay: "60ms" }}>
+                <Typography.Title level={4}>{uiText(language, "crashTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "crashHint")}</Typography.Text>
+                <Divider />
+                <Space direction="vertical" size="small" style={{ width: "100%" }}>
+                    <Input.TextArea
+                        value={crashLog}
+                        onChange={(e) => setCrashLog(e.target.value)}
+                        placeholder={uiText(language, "crashPlaceholder")}
+                        autoSize={{ minRows: 4, maxRows: 12 }}
+                    />
+                    <Button type="primary" onClick={onMatchCrash} loading={crashRunning} disabled={!crashLog.trim()}>
+                        {uiText(language, "crashMatch")}
+                    </Button>
+                </Space>
+                {crashError ? (
+                    <Typography.Text type="danger" className="lookupError">
+                        {crashError}
+                    </Typography.Text>
+                ) : null}
+                {crashMatch ? (
+                    <Space direction="vertical" size="small" className="lookupResult" style={{ width: "100%" }}>
+                        <Space size="small" wrap>
+                            <Tag color={crashMatch.matched_by === "current" ? "default" : "green"}>
+                                {uiText(language, "crashMatchedBy", { by: crashMatch.matched_by })}
+                            </Tag>
+                            {crashMatch.entry ? (
+                                <Typography.Text className="pathValue">{crashMatch.entry.elf_path}</Typography.Text>
+                            ) : null}
+                            {crashMatch.entry?.version ? <Tag>{crashMatch.entry.version}</Tag> : null}
+                        </Space>
+                        {crashMatch.addresses.length === 0 ? (
+                            <Typography.Text type="secondary">{uiText(language, "crashNoAddresses")}</Typography.Text>
+                        ) : (
+                            crashMatch.addresses.map((item, index) => (
+                                <Typography.Text key={`${item.address}-${index}`}>
+                                    <Tag>{item.address}</Tag>
+                                    {describeAddress(item)}
+                                </Typography.Text>
+                            ))
+                        )}
+                    </Space>
+                ) : null}
+            </Card>
+
+            <Card className="pageCard riseIn" style={{ animationDelay: "120ms" }}>
+                <Typography.Title level={4}>{uiText(language, "ramDumpTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "ramDumpHint")}</Typography.Text>
+                <Divider />
+                <Row gutter={[12, 12]}>
+                    <Col xs={24} md={12}>
+                        <Input
+                            value={dumpPath}
+                            placeholder={uiText(language, "ramDumpPath")}
+                            onChange={(e) => setDumpPath(e.target.value)}
+                            addonAfter={
+                                <Typography.Link onClick={onBrowseDump}>{uiText(language, "settingsBrowse")}</Typography.Link>
+                            }
+                        />
+                    </Col>
+                    <Col xs={12} md={5}>
+                        <Input
+                            value={dumpBase}
+                            addonBefore={uiText(language, "ramDumpBase")}
+                            onChange={(e) => setDumpBase(e.target.value)}
+                        />
+                    </Col>
+                    <Col xs={12} md={5}>
+                        <Input
+                            value={fillPattern}
+                            placeholder="0xA5A5A5A5"
+                            addonBefore={uiText(language, "ramDumpPattern")}
+                            onChange={(e) => setFillPattern(e.target.value)}
+                        />
+                    </Col>
+                    <Col xs={24} md={2}>
+                        <Button type="primary" onClick={onAnalyzeDump} disabled={!dumpPath.trim()}>
+                            {uiText(language, "ramDumpRun")}
+                        </Button>
+                    </Col>
+                </Row>
+                {dumpError ? (
+                    <Typography.Text type="danger" className="lookupError">
+                        {dumpError}
+                    </Typography.Text>
+                ) : null}
+                {dumpReport ? (
+                    <Space direction="vertical" size="small" className="lookupResult" style={{ width: "100%" }}>
+                        {dumpReport.findings.map((finding) => (
+                            <Typography.Text key={finding.id}>
+                                <Tag color={finding.severity === "warn" ? "orange" : "blue"}>{finding.id}</Tag>
+                                {finding.items.join(", ")}
+                            </Typography.Text>
+                        ))}
+                        <Table
+                            size="small"
+                            rowKey={(stack) => `${stack.name}-${stack.start}`}
+                            columns={stackColumns}
+                            dataSource={dumpReport.stacks}
+                            pagination={false}
+                        />
+                    </Space>
+                ) : null}
+            </Card>
         </Space>
     );
 }
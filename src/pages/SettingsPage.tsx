@@ -1,14 +1,19 @@
-﻿import { Button, Card, Col, Form, Input, Row, Select, Space, Switch, Typography, message } from "antd";
+﻿import { Button, Card, Col, Form, Input, InputNumber, Row, Select, Space, Switch, Typography, message } from "antd";
 import { open } from "@tauri-apps/plugin-dialog";
 import { invoke } from "@tauri-apps/api/core";
-import { useCallback, useEffect } from "react";
+import { useCallback, useEffect, useState } from "react";
 import { uiText } from "../domain/uiI18n";
 import type { Language } from "../domain/i18n";
 import {type ToolchainCandidate, deriveRootFromNm } from "../domain/toolchain";
 import { useAnalysisStore } from "../store/analysis.store";
-import { useSettingsStore } from "../store/settings.store";
+import { type SectionRule, useSettingsStore } from "../store/settings.store";
 import { useUiStore } from "../store/ui.store";
 
+type ElfLibrary = {
+    directories: string[];
+    entries: { elf_path: string }[];
+};
+
 const statusLabel = (language: Language, status: string) => {
     switch (status) {
         case "running":
@@ -27,6 +32,12 @@ export default function SettingsPage() {
     const toolchain = useSettingsStore((s) => s.toolchain);
     const updateToolchain = useSettingsStore((s) => s.updateToolchain);
     const resetToolchain = useSettingsStore((s) => s.resetToolchain);
+    const sectionRules = useSettingsStore((s) => s.sectionRules);
+    const setSectionRules = useSettingsStore((s) => s.setSectionRules);
+    const regionThresholds = useSettingsStore((s) => s.regionThresholds);
+    const updateRegionThresholds = useSettingsStore((s) => s.updateRegionThresholds);
+    const stackMarginBytes = useSettingsStore((s) => s.stackMarginBytes);
+    const setStackMarginBytes = useSettingsStore((s) => s.setStackMarginBytes);
     const analysisStatus = useAnalysisStore((s) => s.status);
     const analysisError = useAnalysisStore((s) => s.lastError);
     const themeMode = useUiStore((s) => s.theme);
@@ -34,11 +45,37 @@ export default function SettingsPage() {
     const setTheme = useUiStore((s) => s.setTheme);
     const setLanguage = useUiStore((s) => s.setLanguage);
     const [msgApi, contextHolder] = message.useMessage();
+    const [elfLibrary, setElfLibrary] = useState<ElfLibrary | null>(null);
 
     useEffect(() => {
         form.setFieldsValue(toolchain);
     }, [form, toolchain]);
 
+    useEffect(() => {
+        invoke<ElfLibrary>("list_elf_library")
+            .then(setElfLibrary)
+            .catch((error: unknown) => msgApi.error(error instanceof Error ? error.message : String(error)));
+    }, [msgApi]);
+
+    const addLibraryDirectory = async () => {
+        const selected = await open({ directory: true, multiple: false });
+        const directory = Array.isArray(selected) ? selected[0] : selected;
+        if (!directory || typeof directory !== "string") return;
+        try {
+            setElfLibrary(await invoke<ElfLibrary>("register_elf_library", { directory }));
+        } catch (error: unknown) {
+            msgApi.error(error instanceof Error ? error.message : String(error));
+        }
+    };
+
+    const removeLibraryDirectory = async (directory: string) => {
+        try {
+            setElfLibrary(await invoke<ElfLibrary>("remove_elf_library", { directory }));
+        } catch (error: unknown) {
+            msgApi.error(error instanceof Error ? error.message : String(error));
+        }
+    };
+
     const handleBrowseRoot = useCallback(async () => {
         const selected = await open({ directory: true, multiple: false });
         const root = Array.isArray(selected) ? selected[0] : selected;
@@ -91,6 +128,9 @@ export default function SettingsPage() {
         }
     }, [language, msgApi, toolchain, updateToolchain]);
 
+    const updateRule = (index: number, next: Partial<SectionRule>) =>
+        setSectionRules(sectionRules.map((rule, i) => (i === index ? { ...rule, ...next } : rule)));
+
     return (
         <Space direction="vertical" size="large" className="pageStack">
             {contextHolder}
@@ -160,7 +200,122 @@ export default function SettingsPage() {
                 </Form>
             </Card>
 
+            <Card className="pageCard riseIn" style={{ animationDelay: "60ms" }}>
+                <Typography.Title level={4}>{uiText(language, "settingsSectionRulesTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "settingsSectionRulesHint")}</Typography.Text>
+                <Space direction="vertical" size="small" style={{ marginTop: 12, width: "100%" }}>
+                    {sectionRules.map((rule, index) => (
+                        <Row gutter={[12, 12]} key={index} align="middle">
+                            <Col xs={24} md={10}>
+                                <Input
+                                    value={rule.pattern}
+                                    placeholder=".ramfunc*"
+                                    onChange={(e) => updateRule(index, { pattern: e.target.value })}
+                                />
+                            </Col>
+                            <Col xs={12} md={5}>
+                                <Select
+                                    value={rule.class}
+                                    onChange={(value) => updateRule(index, { class: value })}
+                                    options={["text", "rodata", "data", "bss"].map((value) => ({ value, label: value }))}
+                                    style={{ width: "100%" }}
+                                />
+                            </Col>
+                            <Col xs={12} md={5}>
+                                <Select
+                                    value={rule.countsAgainst}
+                                    onChange={(value) => updateRule(index, { countsAgainst: value })}
+                                    options={[
+                                        { value: "flash", label: uiText(language, "settingsSectionRuleFlash") },
+                                        { value: "ram", label: uiText(language, "settingsSectionRuleRam") },
+                                        { value: "both", label: uiText(language, "settingsSectionRuleBoth") },
+                                    ]}
+                                    style={{ width: "100%" }}
+                                />
+                            </Col>
+                            <Col xs={24} md={4}>
+                                <Button danger onClick={() => setSectionRules(sectionRules.filter((_, i) => i !== index))}>
+                                    {uiText(language, "settingsRemove")}
+                                </Button>
+                            </Col>
+                        </Row>
+                    ))}
+                    <Button
+                        onClick={() =>
+                            setSectionRules([...sectionRules, { pattern: "", class: "text", countsAgainst: "flash" }])
+                        }
+                    >
+                        {uiText(language, "settingsSectionRuleAdd")}
+                    </Button>
+                </Space>
+            </Card>
+
             <Card className="pageCard riseIn" style={{ animationDelay: "120ms" }}>
+                <Typography.Title level={4}>{uiText(language, "settingsAnalysisTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "settingsAnalysisHint")}</Typography.Text>
+                <Row gutter={[16, 12]} style={{ marginTop: 12 }}>
+                    <Col xs={24} md={8}>
+                        <Typography.Text>{uiText(language, "settingsRegionWarnPercent")}</Typography.Text>
+                        <InputNumber
+                            value={regionThresholds.warnPercent}
+                            min={0}
+                            max={regionThresholds.criticalPercent - 1}
+                            addonAfter="%"
+                            onChange={(value) => updateRegionThresholds({ warnPercent: value ?? 85 })}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                    <Col xs={24} md={8}>
+                        <Typography.Text>{uiText(language, "settingsRegionCriticalPercent")}</Typography.Text>
+                        <InputNumber
+                            value={regionThresholds.criticalPercent}
+                            min={regionThresholds.warnPercent + 1}
+                            max={100}
+                            addonAfter="%"
+                            onChange={(value) => updateRegionThresholds({ criticalPercent: value ?? 95 })}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                    <Col xs={24} md={8}>
+                        <Typography.Text>{uiText(language, "settingsStackMargin")}</Typography.Text>
+                        <InputNumber
+                            value={stackMarginBytes}
+                            min={0}
+                            step={256}
+                            addonAfter="B"
+                            onChange={(value) => setStackMarginBytes(value ?? 1024)}
+                            style={{ width: "100%" }}
+                        />
+                    </Col>
+                </Row>
+            </Card>
+
+            <Card className="pageCard riseIn" style={{ animationDelay: "180ms" }}>
+                <Typography.Title level={4}>{uiText(language, "settingsElfLibraryTitle")}</Typography.Title>
+                <Typography.Text type="secondary">{uiText(language, "settingsElfLibraryHint")}</Typography.Text>
+                <Space direction="vertical" size="small" style={{ marginTop: 12, width: "100%" }}>
+                    {(elfLibrary?.directories ?? []).map((directory) => (
+                        <Row gutter={[12, 12]} key={directory} align="middle">
+                            <Col xs={24} md={20}>
+                                <Typography.Text className="pathValue">{directory}</Typography.Text>
+                            </Col>
+                            <Col xs={24} md={4}>
+                                <Button danger onClick={() => removeLibraryDirectory(directory)}>
+                                    {uiText(language, "settingsRemove")}
+                                </Button>
+                            </Col>
+                        </Row>
+                    ))}
+                    <Space>
+                        <Button onClick={addLibraryDirectory}>{uiText(language, "settingsElfLibraryAdd")}</Button>
+                        <Typography.Text type="secondary">
+                            {uiText(language, "settingsElfLibraryCount", { count: elfLibrary?.entries.length ?? 0 })}
+                        </Typography.Text>
+                    </Space>
+                </Space>
+            </Card>
+
+            <Card className="pageCard riseIn" style={{ animationDelay: "240ms" }}>
                 <Typography.Title level={4}>{uiText(language, "settingsUiTitle")}</Typography.Title>
                 <Typography.Text type="secondary">{uiText(language, "settingsUiHint")}</Typography.Text>
                 <Row gutter={[16, 12]} style={{ marginTop: 12 }}>
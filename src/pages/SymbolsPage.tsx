@@ -1,4 +1,4 @@
-﻿import { Button, Card, Col, Descriptions, Drawer, Input, Row, Select, Space, Table, Tooltip, Typography } from "antd";
+﻿import { Button, Card, Col, Descriptions, Drawer, Input, Row, Select, Space, Switch, Table, Tooltip, Typography } from "antd";
 import type { ColumnsType } from "antd/es/table";
 import { invoke } from "@tauri-apps/api/core";
 import { useEffect, useMemo, useState } from "react";
@@ -20,6 +20,7 @@ type SymbolInfo = {
     addr?: string;
     kind: string;
     section_guess?: string;
+    section?: string;
 };
 
 type PagedSymbols = {
@@ -30,16 +31,19 @@ type PagedSymbols = {
 type FacetItem = {
     value: string;
     count: number;
+    size: number;
 };
 
 type SymbolFacets = {
     sections: FacetItem[];
+    classes: FacetItem[];
     kinds: FacetItem[];
 };
 
 export default function SymbolsPage() {
     const language = useUiStore((s) => s.language);
     const analysisStatus = useAnalysisStore((s) => s.status);
+    const sessionId = useAnalysisStore((s) => s.result?.meta.cache.key);
     const [query, setQuery] = useState("");
     const [search, setSearch] = useState("");
     const [data, setData] = useState<SymbolInfo[]>([]);
@@ -47,11 +51,14 @@ export default function SymbolsPage() {
     const [page, setPage] = useState(1);
     const [pageSize, setPageSize] = useState(20);
     const [loading, setLoading] = useState(false);
+    const [loadError, setLoadError] = useState<string | null>(null);
     const [sortKey, setSortKey] = useState<"size" | "name" | "kind" | "section_guess">("size");
     const [sortOrder, setSortOrder] = useState<"asc" | "desc">("desc");
     const [sectionFilter, setSectionFilter] = useState<string | null>(null);
+    const [classFilter, setClassFilter] = useState<string | null>(null);
     const [typeFilter, setTypeFilter] = useState<string | null>(null);
-    const [facets, setFacets] = useState<SymbolFacets>({ sections: [], kinds: [] });
+    const [fuzzy, setFuzzy] = useState(false);
+    const [facets, setFacets] = useState<SymbolFacets>({ sections: [], classes: [], kinds: [] });
     const [facetLoading, setFacetLoading] = useState(false);
     const [selectedSymbol, setSelectedSymbol] = useState<SymbolInfo | null>(null);
 
@@ -103,10 +110,11 @@ export default function SymbolsPage() {
             },
             {
                 title: uiText(language, "symbolsColumnSection"),
-                dataIndex: "section_guess",
+                dataIndex: "section",
                 key: "section",
                 ellipsis: true,
                 width: 140,
+                render: (value: string | undefined, record: SymbolInfo) => value || record.section_guess || "--",
                 sorter: true,
                 sortOrder: sortKey === "section_guess" ? (sortOrder === "asc" ? "ascend" : "descend") : undefined,
             },
@@ -116,14 +124,16 @@ export default function SymbolsPage() {
 
     useEffect(() => {
         const load = async () => {
-            if (analysisStatus !== "success") {
+            if (analysisStatus !== "success" || !sessionId) {
                 setData([]);
                 setTotal(0);
                 return;
             }
             setLoading(true);
+            setLoadError(null);
             try {
                 const result = await invoke<PagedSymbols>("list_symbols", {
+                    sessionId,
                     query: {
                         query: search || null,
                         page,
@@ -131,34 +141,43 @@ export default function SymbolsPage() {
                         sort: sortKey,
                         order: sortOrder,
                         section: sectionFilter,
+                        class: classFilter,
                         kind: typeFilter,
+                        fuzzy,
                     },
                 });
                 setData(result.items || []);
                 setTotal(result.total || 0);
+            } catch (error: unknown) {
+                // A malformed query is reported under the search box instead of leaving stale rows.
+                setData([]);
+                setTotal(0);
+                setLoadError(error instanceof Error ? error.message : String(error));
             } finally {
                 setLoading(false);
             }
         };
         load();
-    }, [analysisStatus, page, pageSize, search, sortKey, sortOrder, sectionFilter, typeFilter]);
+    }, [analysisStatus, sessionId, page, pageSize, search, sortKey, sortOrder, sectionFilter, classFilter, typeFilter, fuzzy]);
 
     useEffect(() => {
         const loadFacets = async () => {
-            if (analysisStatus !== "success") {
-                setFacets({ sections: [], kinds: [] });
+            if (analysisStatus !== "success" || !sessionId) {
+                setFacets({ sections: [], classes: [], kinds: [] });
                 return;
             }
             setFacetLoading(true);
             try {
-                const result = await invoke<SymbolFacets>("list_symbol_facets");
+                const result = await invoke<SymbolFacets>("list_symbol_facets", { sessionId });
                 setFacets(result);
+            } catch {
+                setFacets({ sections: [], classes: [], kinds: [] });
             } finally {
                 setFacetLoading(false);
             }
         };
         loadFacets();
-    }, [analysisStatus]);
+    }, [analysisStatus, sessionId]);
 
     const onSearch = () => {
         setPage(1);
@@ -169,20 +188,26 @@ export default function SymbolsPage() {
         <Space direction="vertical" size="large" className="pageStack">
             <Card className="pageCard riseIn">
                 <Row gutter={[12, 12]} align="middle">
-                    <Col xs={24} md={10}>
+                    <Col xs={24} md={8}>
                         <Input.Search
                             placeholder={uiText(language, "symbolsSearchPlaceholder")}
                             value={query}
                             onChange={(e) => setQuery(e.target.value)}
                             onSearch={onSearch}
                             allowClear
+                            status={loadError ? "error" : undefined}
                         />
+                        {loadError ? (
+                            <Typography.Text type="danger" className="lookupError">
+                                {loadError}
+                            </Typography.Text>
+                        ) : null}
                     </Col>
                     <Col xs={12} md={4}>
                         <Select
                             placeholder={uiText(language, "symbolsSectionPlaceholder")}
                             options={facets.sections.map((item) => ({
-                                label: `${item.value} (${item.count})`,
+                                label: `${item.value} (${item.count}, ${formatBytes(item.size)})`,
                                 value: item.value,
                             }))}
                             allowClear
@@ -195,7 +220,24 @@ export default function SymbolsPage() {
                             }}
                         />
                     </Col>
-                    <Col xs={12} md={4}>
+                    <Col xs={12} md={3}>
+                        <Select
+                            placeholder={uiText(language, "symbolsClassPlaceholder")}
+                            options={facets.classes.map((item) => ({
+                                label: `${item.value} (${item.count})`,
+                                value: item.value,
+                            }))}
+                            allowClear
+                            style={{ width: "100%" }}
+                            loading={facetLoading}
+                            value={classFilter ?? undefined}
+                            onChange={(value) => {
+                                setClassFilter(value ?? null);
+                                setPage(1);
+                            }}
+                        />
+                    </Col>
+                    <Col xs={12} md={3}>
                         <Select
                             placeholder={uiText(language, "symbolsTypePlaceholder")}
                             options={facets.kinds.map((item) => ({
@@ -218,6 +260,15 @@ export default function SymbolsPage() {
                                 {uiText(language, "symbolsApplyFilters")}
                             </Button>
                             <Button disabled>{uiText(language, "symbolsExport")}</Button>
+                            <Switch
+                                checked={fuzzy}
+                                checkedChildren={uiText(language, "symbolsFuzzy")}
+                                unCheckedChildren={uiText(language, "symbolsFuzzy")}
+                                onChange={(checked) => {
+                                    setFuzzy(checked);
+                                    setPage(1);
+                                }}
+                            />
                         </Space>
                     </Col>
                 </Row>
@@ -292,6 +343,9 @@ export default function SymbolsPage() {
                                 {selectedSymbol.kind}
                             </Descriptions.Item>
                             <Descriptions.Item label={uiText(language, "symbolsDetailSection")}>
+                                {selectedSymbol.section || "--"}
+                            </Descriptions.Item>
+                            <Descriptions.Item label={uiText(language, "symbolsDetailClass")}>
                                 {selectedSymbol.section_guess || "--"}
                             </Descriptions.Item>
                         </Descriptions>
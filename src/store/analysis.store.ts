@@ -4,6 +4,7 @@ import { createJSONStorage, persist } from "zustand/middleware";
 type AnalysisInputs = {
     elfPath: string;
     mapPath: string;
+    svdPath: string;
 };
 
 type SectionTotals = {
@@ -23,6 +24,7 @@ type SymbolInfo = {
     addr?: string;
     kind: string;
     section_guess?: string;
+    section?: string;
 };
 
 type ObjectContribution = {
@@ -58,6 +60,7 @@ type CacheMeta = {
 };
 
 type AnalysisMeta = {
+    elf_path: string;
     cache: CacheMeta;
 };
 
@@ -91,6 +94,7 @@ type AnalysisState = {
 const defaultInputs: AnalysisInputs = {
     elfPath: "",
     mapPath: "",
+    svdPath: "",
 };
 
 export const useAnalysisStore = create<AnalysisState>()(
@@ -10,10 +10,27 @@ type ToolchainSettings = {
     lastDetected: string;
 };
 
+export type SectionRule = {
+    pattern: string;
+    class: "text" | "rodata" | "data" | "bss";
+    countsAgainst: "flash" | "ram" | "both";
+};
+
+export type RegionThresholds = {
+    warnPercent: number;
+    criticalPercent: number;
+};
+
 type SettingsState = {
     toolchain: ToolchainSettings;
+    sectionRules: SectionRule[];
+    regionThresholds: RegionThresholds;
+    stackMarginBytes: number;
     updateToolchain: (next: Partial<ToolchainSettings>) => void;
     resetToolchain: () => void;
+    setSectionRules: (rules: SectionRule[]) => void;
+    updateRegionThresholds: (next: Partial<RegionThresholds>) => void;
+    setStackMarginBytes: (bytes: number) => void;
 };
 
 const defaultToolchain: ToolchainSettings = {
@@ -25,10 +42,18 @@ const defaultToolchain: ToolchainSettings = {
     lastDetected: "",
 };
 
+const defaultRegionThresholds: RegionThresholds = {
+    warnPercent: 85,
+    criticalPercent: 95,
+};
+
 export const useSettingsStore = create<SettingsState>()(
     persist(
         (set) => ({
             toolchain: defaultToolchain,
+            sectionRules: [],
+            regionThresholds: defaultRegionThresholds,
+            stackMarginBytes: 1024,
             updateToolchain: (next) =>
                 set((state) => ({
                     toolchain: {
@@ -37,6 +62,15 @@ export const useSettingsStore = create<SettingsState>()(
                     },
                 })),
             resetToolchain: () => set({ toolchain: defaultToolchain }),
+            setSectionRules: (rules) => set({ sectionRules: rules }),
+            updateRegionThresholds: (next) =>
+                set((state) => ({
+                    regionThresholds: {
+                        ...state.regionThresholds,
+                        ...next,
+                    },
+                })),
+            setStackMarginBytes: (bytes) => set({ stackMarginBytes: bytes }),
         }),
         {
             name: "linkerlens-settings-v1",